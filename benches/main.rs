@@ -22,6 +22,23 @@ fn compare_datetime_ok_speedate(bench: &mut Bencher) {
     })
 }
 
+#[bench]
+fn datetime_fixed_utc_fast_path(bench: &mut Bencher) {
+    let s = black_box("2000-01-01T00:02:03Z");
+    bench.iter(|| {
+        black_box(DateTime::parse_str(s).unwrap());
+    })
+}
+
+#[bench]
+fn datetime_fixed_utc_lowercase(bench: &mut Bencher) {
+    // same format but with lowercase separators, so parsed without the fast path
+    let s = black_box("2000-01-01t00:02:03z");
+    bench.iter(|| {
+        black_box(DateTime::parse_str(s).unwrap());
+    })
+}
+
 #[bench]
 fn compare_datetime_ok_iso8601(bench: &mut Bencher) {
     let s = black_box("2000-01-01T00:02:03Z");
@@ -123,7 +140,7 @@ fn compare_timestamp_ok_chrono(bench: &mut Bencher) {
     use chrono::{Datelike, Timelike};
     let ts = black_box(1654617803);
     bench.iter(|| {
-        let dt = chrono::NaiveDateTime::from_timestamp_opt(ts, 0).unwrap();
+        let dt = chrono::DateTime::from_timestamp(ts, 0).unwrap().naive_utc();
         black_box((
             dt.year(),
             dt.month(),
@@ -249,6 +266,23 @@ fn format_date_time(bench: &mut Bencher) {
     })
 }
 
+#[bench]
+fn parse_timestamp_ms_fraction_str(bench: &mut Bencher) {
+    let timestamps = black_box([
+        "1711445175471.865",
+        "1711445175471.5",
+        "1654646404000.123",
+        "-1654646404000.123",
+        "253402300799000.999",
+    ]);
+
+    bench.iter(|| {
+        for timestamp in &timestamps {
+            black_box(DateTime::parse_str(black_box(*timestamp)).unwrap());
+        }
+    });
+}
+
 #[bench]
 fn parse_timestamp_str(bench: &mut Bencher) {
     let timestamps = black_box([
@@ -270,3 +304,43 @@ fn parse_timestamp_str(bench: &mut Bencher) {
         }
     });
 }
+
+#[bench]
+fn duration_iso_year(bench: &mut Bencher) {
+    let s = black_box("P1Y");
+    bench.iter(|| {
+        black_box(Duration::parse_str(s).unwrap());
+    })
+}
+
+#[bench]
+fn duration_iso_minutes(bench: &mut Bencher) {
+    let s = black_box("PT30M");
+    bench.iter(|| {
+        black_box(Duration::parse_str(s).unwrap());
+    })
+}
+
+#[bench]
+fn duration_iso_full(bench: &mut Bencher) {
+    let s = black_box("P1Y2M3DT4H5M6S");
+    bench.iter(|| {
+        black_box(Duration::parse_str(s).unwrap());
+    })
+}
+
+#[bench]
+fn duration_iso_fraction(bench: &mut Bencher) {
+    let s = black_box("PT1.5S");
+    bench.iter(|| {
+        black_box(Duration::parse_str(s).unwrap());
+    })
+}
+
+#[bench]
+fn duration_time_format(bench: &mut Bencher) {
+    let s = black_box("12:30:45");
+    bench.iter(|| {
+        black_box(Duration::parse_str(s).unwrap());
+    })
+}
@@ -0,0 +1,9 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use speedate::Date;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(d) = Date::parse_bytes(data) {
+        assert_eq!(d.checked_timestamp(), Some(d.timestamp()), "{:?} => {}", data, d);
+    }
+});
@@ -0,0 +1,21 @@
+//! Compile check that speedate can be used from a `no_std` crate, build with a target which doesn't
+//! provide `std`, e.g. `cargo build --target thumbv7em-none-eabihf`
+#![no_std]
+
+use speedate::{Date, DateTime, Duration, ParseError, Time};
+
+pub fn parse_date(bytes: &[u8]) -> Result<Date, ParseError> {
+    Date::parse_bytes(bytes)
+}
+
+pub fn parse_time(bytes: &[u8]) -> Result<Time, ParseError> {
+    Time::parse_bytes(bytes)
+}
+
+pub fn parse_datetime(bytes: &[u8]) -> Result<DateTime, ParseError> {
+    DateTime::parse_bytes(bytes)
+}
+
+pub fn parse_duration(bytes: &[u8]) -> Result<Duration, ParseError> {
+    Duration::parse_bytes(bytes)
+}
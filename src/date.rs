@@ -1,13 +1,19 @@
-use std::fmt;
-use std::str::FromStr;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
 
 use crate::numbers::int_parse_bytes;
-use crate::{get_digit_unchecked, DateTime, ParseError};
+use crate::{get_digit, get_digit_unchecked, ParseError, Time, TimeConfig, TimeConfigBuilder, TimestampUnit};
 
 /// A Date
 ///
 /// Allowed formats:
 /// * `YYYY-MM-DD`
+/// * `YY-MM-DD` - only when [DateConfig::two_digit_year_pivot] is set and [DateConfig::field_order] is `Ymd`
+/// * `YYYY-Mon-DD` and `DD Month YYYY` - only via [Date::parse_named_month_bytes]
+/// * `DD/MM/YYYY` and `MM/DD/YYYY` - only when [DateConfig::field_order] is set, `-` and `.` are also
+///   allowed as separators
 ///
 /// Leap years are correct calculated according to the Gregorian calendar.
 /// Thus `2000-02-29` is a valid date, but `2001-02-29` is not.
@@ -39,7 +45,73 @@ impl fmt::Display for Date {
         crate::display_num_buf(4, 0, self.year as u32, &mut buf);
         crate::display_num_buf(2, 5, self.month as u32, &mut buf);
         crate::display_num_buf(2, 8, self.day as u32, &mut buf);
-        f.write_str(std::str::from_utf8(&buf[..]).unwrap())
+        f.write_str(core::str::from_utf8(&buf[..]).unwrap())
+    }
+}
+
+impl Default for Date {
+    /// The unix epoch, `1970-01-01`
+    fn default() -> Self {
+        Self {
+            year: 1970,
+            month: 1,
+            day: 1,
+        }
+    }
+}
+
+impl Add<i64> for Date {
+    type Output = Self;
+
+    /// Add a number of days to the date, see [Date::add_days].
+    ///
+    /// # Panics
+    ///
+    /// If the result would be outside the supported range of `0000-01-01` to `9999-12-31`, use
+    /// [Date::add_days] to handle this case.
+    fn add(self, days: i64) -> Self {
+        match self.add_days(days) {
+            Ok(date) => date,
+            Err(e) => panic!("date out of range: {e}"),
+        }
+    }
+}
+
+impl Sub<i64> for Date {
+    type Output = Self;
+
+    /// Subtract a number of days from the date, see [Date::add_days].
+    ///
+    /// # Panics
+    ///
+    /// If the result would be outside the supported range of `0000-01-01` to `9999-12-31`, use
+    /// [Date::add_days] with a negative number of days to handle this case.
+    fn sub(self, days: i64) -> Self {
+        match days.checked_neg() {
+            Some(days) => self.add(days),
+            // subtracting `i64::MIN` days would add more days than any date supports
+            None => panic!("date out of range: {}", ParseError::DateTooLarge),
+        }
+    }
+}
+
+impl Sub<Date> for Date {
+    type Output = i64;
+
+    /// Number of days from `other` to `self`, negative if `other` is after `self`.
+    fn sub(self, other: Date) -> i64 {
+        (self.timestamp() - other.timestamp()) / 86_400
+    }
+}
+
+impl TryFrom<i64> for Date {
+    type Error = ParseError;
+
+    /// Convert a unix timestamp in seconds or milliseconds to a date as with numeric input to
+    /// [Date::parse_bytes], the timestamp must be exactly midnight, see [Date::from_timestamp].
+    #[inline]
+    fn try_from(timestamp: i64) -> Result<Self, Self::Error> {
+        Self::from_timestamp(timestamp, true)
     }
 }
 
@@ -61,8 +133,24 @@ pub(crate) const MS_WATERSHED: i64 = 20_000_000_000;
 const UNIX_9999: i64 = 253_402_300_799;
 // 0000-01-01T00:00:00+00:00 as a unix timestamp, used as min allowed value below
 const UNIX_0000: i64 = -62_167_219_200;
+// Julian Day Number of 1970-01-01
+const JDN_UNIX_EPOCH: i64 = 2_440_588;
 
 impl Date {
+    /// The earliest supported date, `0000-01-01`.
+    pub const MIN: Self = Self {
+        year: 0,
+        month: 1,
+        day: 1,
+    };
+
+    /// The latest supported date, `9999-12-31`.
+    pub const MAX: Self = Self {
+        year: 9999,
+        month: 12,
+        day: 31,
+    };
+
     /// Parse a date from a string using RFC 3339 format
     ///
     /// # Arguments
@@ -114,6 +202,36 @@ impl Date {
         Self::parse_bytes(str.as_bytes())
     }
 
+    /// Parse a list of dates separated by `delim` using [Date::parse_str], the first error is returned tagged
+    /// with the index of the date which failed.
+    ///
+    /// An empty string gives an empty list.
+    ///
+    /// # Arguments
+    ///
+    /// * `str` - The string to parse
+    /// * `delim` - The character separating dates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, ParseError};
+    ///
+    /// let dates = Date::parse_list("2020-01-01,2020-02-01", ',').unwrap();
+    /// assert_eq!(dates[1].to_string(), "2020-02-01");
+    ///
+    /// assert_eq!(Date::parse_list("2020-01-01,foobar", ','), Err((1, ParseError::TooShort)));
+    /// ```
+    pub fn parse_list(str: &str, delim: char) -> Result<Vec<Self>, (usize, ParseError)> {
+        if str.is_empty() {
+            return Ok(Vec::new());
+        }
+        str.split(delim)
+            .enumerate()
+            .map(|(index, input)| Self::parse_str(input).map_err(|e| (index, e)))
+            .collect()
+    }
+
     /// Parse a date from bytes using RFC 3339 format
     ///
     /// # Arguments
@@ -147,6 +265,59 @@ impl Date {
         Ok(d)
     }
 
+    /// Same as `Date::parse_bytes_rfc3339` but with a `DateConfig`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    /// * `config` - The `DateConfig` to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, DateConfigBuilder};
+    ///
+    /// let config = DateConfigBuilder::new().two_digit_year_pivot(Some(50)).build();
+    /// let d = Date::parse_bytes_rfc3339_with_config(b"20-01-01", &config).unwrap();
+    /// assert_eq!(d.to_string(), "2020-01-01");
+    /// ```
+    pub fn parse_bytes_rfc3339_with_config(bytes: &[u8], config: &DateConfig) -> Result<Self, ParseError> {
+        config.check_input_len(bytes)?;
+        let bytes = config.trim(bytes);
+        let (d, length) = match (config.field_order, config.two_digit_year_pivot) {
+            (DateFieldOrder::Dmy | DateFieldOrder::Mdy, _) if matches!(bytes.get(2), Some(b'/' | b'-' | b'.')) => {
+                (Self::parse_bytes_partial_year_last(bytes, config.field_order)?, 10)
+            }
+            (_, Some(pivot)) if bytes.get(2).copied() == Some(b'-') => {
+                (Self::parse_bytes_partial_two_digit_year(bytes, pivot)?, 8)
+            }
+            _ => (Self::parse_bytes_partial(bytes)?, 10),
+        };
+
+        if bytes.len() > length {
+            if config.allow_time_suffix && matches!(bytes[length], b'T' | b't' | b'_' | b' ') {
+                Self::check_midnight_suffix(bytes, length + 1)?;
+            } else {
+                return Err(ParseError::ExtraCharacters);
+            }
+        }
+
+        if config.forbid_year_zero && d.year == 0 {
+            return Err(ParseError::YearZero);
+        }
+
+        Ok(d)
+    }
+
+    /// Check the time starting at `offset` is exactly midnight, used for [DateConfig::allow_time_suffix].
+    fn check_midnight_suffix(bytes: &[u8], offset: usize) -> Result<(), ParseError> {
+        let time = Time::parse_bytes_offset(bytes, offset, &TimeConfigBuilder::new().build())?;
+        if time.hour != 0 || time.minute != 0 || time.second != 0 || time.microsecond != 0 {
+            return Err(ParseError::DateNotExact);
+        }
+        Ok(())
+    }
+
     /// Parse a date from bytes using RFC 3339 format, or a unix timestamp.
     ///
     /// In the input is purely numeric, then the number is interpreted as a unix timestamp,
@@ -173,11 +344,223 @@ impl Date {
             Ok(d) => Ok(d),
             Err(e) => match int_parse_bytes(bytes) {
                 Some(int) => Self::from_timestamp(int, true),
+                None if crate::is_relative_keyword(bytes) => Err(ParseError::RelativeNotSupported),
                 None => Err(e),
             },
         }
     }
 
+    /// Same as `Date::parse_bytes` but with a `DateConfig`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    /// * `config` - The `DateConfig` to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, DateConfigBuilder};
+    ///
+    /// let config = DateConfigBuilder::new().two_digit_year_pivot(Some(50)).build();
+    /// let d = Date::parse_bytes_with_config(b"99-01-01", &config).unwrap();
+    /// assert_eq!(d.to_string(), "1999-01-01");
+    ///
+    /// let d = Date::parse_bytes_with_config(b"1577836800", &config).unwrap();
+    /// assert_eq!(d.to_string(), "2020-01-01");
+    /// ```
+    pub fn parse_bytes_with_config(bytes: &[u8], config: &DateConfig) -> Result<Self, ParseError> {
+        config.check_input_len(bytes)?;
+        let bytes = config.trim(bytes);
+        match Self::parse_bytes_rfc3339_with_config(bytes, config) {
+            Ok(d) => Ok(d),
+            Err(e) => match int_parse_bytes(bytes) {
+                Some(_)
+                    if config
+                        .min_timestamp_digits
+                        .is_some_and(|min| Self::digit_count(bytes) < min) =>
+                {
+                    Err(e)
+                }
+                Some(int) => Self::from_timestamp(int, true),
+                None if crate::is_relative_keyword(bytes) => Err(ParseError::RelativeNotSupported),
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Number of ASCII digits in `bytes`, ignoring any sign.
+    fn digit_count(bytes: &[u8]) -> usize {
+        bytes.iter().filter(|c| c.is_ascii_digit()).count()
+    }
+
+    /// Parse a recurring date without a year in the ISO 8601 `--MM-DD` format, e.g. `--12-25`, returns the
+    /// month and day.
+    ///
+    /// Since there's no year, the day is validated against the longest the month can be, so `--02-29` is
+    /// accepted as it's valid in leap years.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, ParseError};
+    ///
+    /// assert_eq!(Date::parse_month_day_bytes(b"--12-25"), Ok((12, 25)));
+    /// assert_eq!(Date::parse_month_day_bytes(b"--02-30"), Err(ParseError::OutOfRangeDay));
+    /// ```
+    pub fn parse_month_day_bytes(bytes: &[u8]) -> Result<(u8, u8), ParseError> {
+        match bytes.len() {
+            0..7 => return Err(ParseError::TooShort),
+            7 => (),
+            _ => return Err(ParseError::ExtraCharacters),
+        }
+        if bytes[0] != b'-' {
+            return Err(ParseError::InvalidCharDateSep);
+        }
+        let (month, day) = unsafe { Self::parse_month_day_unchecked(bytes, 1)? };
+        // 2000 is a leap year, so February has 29 days
+        Self::check_month_day(2000, month, day)?;
+        Ok((month, day))
+    }
+
+    /// Parse a year and month without a day in the ISO 8601 `YYYY-MM` format, e.g. `2020-06` for the whole of
+    /// June 2020, returns the year and month.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, ParseError};
+    ///
+    /// assert_eq!(Date::parse_year_month_bytes(b"2020-06"), Ok((2020, 6)));
+    /// assert_eq!(Date::parse_year_month_bytes(b"2020-13"), Err(ParseError::OutOfRangeMonth));
+    /// ```
+    pub fn parse_year_month_bytes(bytes: &[u8]) -> Result<(u16, u8), ParseError> {
+        match bytes.len() {
+            0..7 => return Err(ParseError::TooShort),
+            7 => (),
+            _ => return Err(ParseError::ExtraCharacters),
+        }
+        let y1 = get_digit!(bytes, 0, InvalidCharYear) as u16;
+        let y2 = get_digit!(bytes, 1, InvalidCharYear) as u16;
+        let y3 = get_digit!(bytes, 2, InvalidCharYear) as u16;
+        let y4 = get_digit!(bytes, 3, InvalidCharYear) as u16;
+        if bytes[4] != b'-' {
+            return Err(ParseError::InvalidCharDateSep);
+        }
+        let m1 = get_digit!(bytes, 5, InvalidCharMonth);
+        let m2 = get_digit!(bytes, 6, InvalidCharMonth);
+        let month = m1 * 10 + m2;
+        if !(1..=12).contains(&month) {
+            return Err(ParseError::OutOfRangeMonth);
+        }
+        Ok((y1 * 1000 + y2 * 100 + y3 * 10 + y4, month))
+    }
+
+    /// Parse a date from a string where the month is given by its English name, see
+    /// [Date::parse_named_month_bytes].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_named_month_str("2020-Jan-01").unwrap();
+    /// assert_eq!(d.to_string(), "2020-01-01");
+    /// ```
+    #[inline]
+    pub fn parse_named_month_str(str: &str) -> Result<Self, ParseError> {
+        Self::parse_named_month_bytes(str.as_bytes())
+    }
+
+    /// Parse a date from bytes where the month is given by its English name.
+    ///
+    /// Allowed formats:
+    /// * `YYYY-Mon-DD` or `YYYY-Month-DD`, e.g. `2020-Jan-01` or `2020-January-01`
+    /// * `DD Mon YYYY` or `DD Month YYYY`, e.g. `01 Jan 2020` or `1 January 2020`
+    ///
+    /// Month names are matched case-insensitively, either in full or abbreviated to three letters.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_named_month_bytes(b"2020-January-01").unwrap();
+    /// assert_eq!(d.to_string(), "2020-01-01");
+    ///
+    /// let d = Date::parse_named_month_bytes(b"01 January 2020").unwrap();
+    /// assert_eq!(d.to_string(), "2020-01-01");
+    /// ```
+    pub fn parse_named_month_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        if bytes.len() < 10 {
+            return Err(ParseError::TooShort);
+        }
+        let (year, month, day) = if bytes[..4].iter().all(u8::is_ascii_digit) {
+            // YYYY-Mon-DD
+            let year = parse_year(bytes)?;
+            if bytes[4] != b'-' {
+                return Err(ParseError::InvalidCharDateSep);
+            }
+            let name_len = month_name_len(&bytes[5..]);
+            let month = parse_month_name(&bytes[5..5 + name_len])?;
+            let rest = &bytes[5 + name_len..];
+            match rest.first() {
+                Some(b'-') => (),
+                Some(_) => return Err(ParseError::InvalidCharDateSep),
+                None => return Err(ParseError::TooShort),
+            }
+            let d1 = get_digit!(rest, 1, InvalidCharDay);
+            let d2 = get_digit!(rest, 2, InvalidCharDay);
+            if rest.len() > 3 {
+                return Err(ParseError::ExtraCharacters);
+            }
+            (year, month, d1 * 10 + d2)
+        } else {
+            // DD Month YYYY, where the day may be a single digit
+            let mut day = get_digit!(bytes, 0, InvalidCharDay);
+            let mut position = 1;
+            if let Some(c) = bytes.get(1).filter(|c| c.is_ascii_digit()) {
+                day = day * 10 + (c - b'0');
+                position = 2;
+            }
+            if bytes[position] != b' ' {
+                return Err(ParseError::InvalidCharDateSep);
+            }
+            position += 1;
+            let name_len = month_name_len(&bytes[position..]);
+            let month = parse_month_name(&bytes[position..position + name_len])?;
+            position += name_len;
+            match bytes.get(position) {
+                Some(b' ') => (),
+                Some(_) => return Err(ParseError::InvalidCharDateSep),
+                None => return Err(ParseError::TooShort),
+            }
+            let rest = &bytes[position + 1..];
+            if rest.len() < 4 {
+                return Err(ParseError::TooShort);
+            }
+            let year = parse_year(rest)?;
+            if rest.len() > 4 {
+                return Err(ParseError::ExtraCharacters);
+            }
+            (year, month, day)
+        };
+
+        Self::check_month_day(year, month, day)
+    }
+
     /// Create a date from a Unix Timestamp in seconds or milliseconds
     ///
     /// ("Unix Timestamp" means number of seconds or milliseconds since 1970-01-01)
@@ -215,6 +598,30 @@ impl Date {
         Ok(d)
     }
 
+    /// Like [Date::from_timestamp] but also returns the time of day rather than discarding it.
+    ///
+    /// The timestamp is interpreted as seconds or milliseconds as described in [Date::from_timestamp], the
+    /// timezone offset of the time is taken from [TimeConfig::unix_timestamp_offset].
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - timestamp in either seconds or milliseconds
+    /// * `config` - the `TimeConfig` to use for the time
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, TimeConfigBuilder};
+    ///
+    /// let (d, t) = Date::from_timestamp_with_remainder(1_654_601_594, &TimeConfigBuilder::new().build()).unwrap();
+    /// assert_eq!(d.to_string(), "2022-06-07");
+    /// assert_eq!(t.to_string(), "11:33:14");
+    /// ```
+    pub fn from_timestamp_with_remainder(timestamp: i64, config: &TimeConfig) -> Result<(Self, Time), ParseError> {
+        let dt = crate::DateTime::from_timestamp_with_config(timestamp, 0, config)?;
+        Ok((dt.date, dt.time))
+    }
+
     /// Unix timestamp in seconds (number of seconds between self and 1970-01-01)
     ///
     /// # Example
@@ -231,11 +638,81 @@ impl Date {
         days * 86400 + UNIX_0000
     }
 
-    /// Current date. Internally, this uses [DateTime::now].
+    /// As with [Date::timestamp] but using checked arithmetic, `None` is returned if any step overflows.
+    ///
+    /// Since `year` is a `u16` this can't overflow for any `Date`, in particular it is always `Some` for dates
+    /// between `0000-01-01` and `9999-12-31`, it exists for code which prefers not to rely on that.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2022-06-07").unwrap();
+    /// assert_eq!(d.checked_timestamp(), Some(1_654_560_000));
+    /// ```
+    pub fn checked_timestamp(&self) -> Option<i64> {
+        let days = (self.year as i64)
+            .checked_mul(365)?
+            .checked_add((self.ordinal_day() as i64).checked_sub(1)?)?
+            .checked_add(intervening_leap_years(self.year as i64))?;
+        days.checked_mul(86400)?.checked_add(UNIX_0000)
+    }
+
+    /// Unix timestamp in milliseconds, as with [Date::timestamp] multiplied by 1000.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2022-06-07").unwrap();
+    /// assert_eq!(d.timestamp_ms(), 1_654_560_000_000);
+    /// ```
+    pub fn timestamp_ms(&self) -> i64 {
+        self.timestamp() * 1000
+    }
+
+    /// As with [Date::timestamp_ms] but using checked arithmetic, `None` is returned if any step overflows.
+    ///
+    /// As with [Date::checked_timestamp], this is always `Some` for any `Date`, the largest value, for
+    /// `65535-12-31`, is around `2e15` which is well within the range of `i64`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("9999-12-31").unwrap();
+    /// assert_eq!(d.checked_timestamp_ms(), Some(253_402_214_400_000));
+    /// ```
+    pub fn checked_timestamp_ms(&self) -> Option<i64> {
+        self.checked_timestamp()?.checked_mul(1000)
+    }
+
+    /// Unix timestamps in seconds bounding the day, the start is inclusive and the end exclusive.
+    ///
+    /// The start is midnight at the beginning of the day, the end is midnight at the beginning of the
+    /// following day, so an instant `t` is on this date if `start <= t < end`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2022-06-07").unwrap();
+    /// assert_eq!(d.timestamp_range(), (1_654_560_000, 1_654_646_400));
+    /// ```
+    pub fn timestamp_range(&self) -> (i64, i64) {
+        let start = self.timestamp();
+        (start, start + 86_400)
+    }
+
+    /// Current date. Internally, this uses [crate::DateTime::now].
     ///
     /// # Arguments
     ///
-    /// * `tz_offset` - timezone offset in seconds, meaning as per [DateTime::now], must be less than `86_400`
+    /// * `tz_offset` - timezone offset in seconds, meaning as per [crate::DateTime::now], must be less than `86_400`
     ///
     /// # Example
     ///
@@ -245,8 +722,43 @@ impl Date {
     /// let d = Date::today(0).unwrap();
     /// println!("The date today is: {}", d)
     /// ```
+    #[cfg(feature = "std")]
     pub fn today(tz_offset: i32) -> Result<Self, ParseError> {
-        Ok(DateTime::now(tz_offset)?.date)
+        Ok(crate::DateTime::now(tz_offset)?.date)
+    }
+
+    /// Parse a date read from `reader`, as with [Date::parse_bytes] both `YYYY-MM-DD` dates and unix
+    /// timestamps are supported.
+    ///
+    /// At most 16 bytes are read, one more than the longest valid input, a negative millisecond timestamp,
+    /// see [crate::DateTime::parse_reader] for how longer inputs and trailing bytes are handled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_reader(&mut Cursor::new(b"2022-01-01")).unwrap();
+    /// assert_eq!(d.to_string(), "2022-01-01");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_reader<R: std::io::Read>(reader: &mut R) -> Result<Self, ParseError> {
+        Self::parse_bytes(&crate::read_bounded(reader, 15)?)
+    }
+
+    /// Description of the format produced by `to_string()`, e.g. for use as a format hint in JSON Schema
+    /// or OpenAPI documents, this is a static descriptor and isn't used when parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// assert_eq!(Date::format_hint(), "%Y-%m-%d");
+    /// ```
+    pub const fn format_hint() -> &'static str {
+        "%Y-%m-%d"
     }
 
     /// Day of the year, starting from 1.
@@ -270,9 +782,261 @@ impl Date {
         }
     }
 
+    /// Create a date from the year and day of the year starting from 1, the inverse of [Date::ordinal_day].
+    ///
+    /// `Err(ParseError::OutOfRangeDay)` is returned if `ordinal` is zero or more than the number of days in
+    /// the year, 365 or 366 in leap years.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::from_year_ordinal(2020, 60).unwrap();
+    /// assert_eq!(d.to_string(), "2020-02-29");
+    /// assert_eq!(d.ordinal_day(), 60);
+    /// ```
+    pub fn from_year_ordinal(year: u16, ordinal: u16) -> Result<Self, ParseError> {
+        let leap_year = is_leap_year(year);
+        let days_in_year = if leap_year { 366 } else { 365 };
+        if ordinal < 1 || ordinal > days_in_year {
+            return Err(ParseError::OutOfRangeDay);
+        }
+        let (month, day) = match leap_year {
+            true => leap_year_month_day(ordinal as i16),
+            false => common_year_month_day(ordinal as i16),
+        };
+        Ok(Self { year, month, day })
+    }
+
+    /// Clone the date and set a new year, the day is revalidated so `with_year(2021)` on `2020-02-29` returns
+    /// `Err(ParseError::OutOfRangeDay)`, `Err(ParseError::DateTooLarge)` is returned if `year` is more than 9999.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2020-02-29").unwrap();
+    /// assert_eq!(d.with_year(2024).unwrap().to_string(), "2024-02-29");
+    /// assert!(d.with_year(2021).is_err());
+    /// ```
+    pub fn with_year(&self, year: u16) -> Result<Self, ParseError> {
+        if year > 9999 {
+            return Err(ParseError::DateTooLarge);
+        }
+        Self::check_month_day(year, self.month, self.day)
+    }
+
+    /// Clone the date and set a new month, returns `Err(ParseError::OutOfRangeMonth)` if `month` isn't from
+    /// 1 to 12, or `Err(ParseError::OutOfRangeDay)` if the day doesn't exist in the new month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2020-01-31").unwrap();
+    /// assert_eq!(d.with_month(3).unwrap().to_string(), "2020-03-31");
+    /// assert!(d.with_month(2).is_err());
+    /// ```
+    pub fn with_month(&self, month: u8) -> Result<Self, ParseError> {
+        Self::check_month_day(self.year, month, self.day)
+    }
+
+    /// Clone the date and set a new day, returns `Err(ParseError::OutOfRangeDay)` if the day doesn't exist in
+    /// the month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2020-02-01").unwrap();
+    /// assert_eq!(d.with_day(29).unwrap().to_string(), "2020-02-29");
+    /// assert!(d.with_day(30).is_err());
+    /// ```
+    pub fn with_day(&self, day: u8) -> Result<Self, ParseError> {
+        Self::check_month_day(self.year, self.month, day)
+    }
+
+    /// Day of the week, where Monday is `0` and Sunday is `6`, matching python's `date.weekday()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2022-06-07").unwrap();
+    /// assert_eq!(d.weekday(), 1);
+    /// ```
+    pub fn weekday(&self) -> u8 {
+        // 1970-01-01 was a Thursday
+        (self.timestamp().div_euclid(86_400) + 3).rem_euclid(7) as u8
+    }
+
+    /// Julian Day Number of the date, the number of days since the start of the Julian Period on
+    /// 4714-11-24 BC in the proleptic Gregorian calendar.
+    ///
+    /// Strictly Julian days start at noon, this is the number for the day beginning at noon on this date,
+    /// as conventionally used for a calendar date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("1970-01-01").unwrap();
+    /// assert_eq!(d.julian_day_number(), 2_440_588);
+    /// ```
+    pub fn julian_day_number(&self) -> i64 {
+        self.timestamp().div_euclid(86_400) + JDN_UNIX_EPOCH
+    }
+
+    /// Create a date from a Julian Day Number, the inverse of [Date::julian_day_number].
+    ///
+    /// Returns `Err(ParseError::DateTooSmall)` or `Err(ParseError::DateTooLarge)` if the date would be
+    /// outside the supported range of `0000-01-01` to `9999-12-31`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::from_julian_day_number(2_451_545).unwrap();
+    /// assert_eq!(d.to_string(), "2000-01-01");
+    /// ```
+    pub fn from_julian_day_number(jdn: i64) -> Result<Self, ParseError> {
+        match jdn.checked_sub(JDN_UNIX_EPOCH) {
+            Some(days) => Self::default().add_days(days),
+            None => Err(ParseError::DateTooSmall),
+        }
+    }
+
+    /// Add a number of days to the date, `days` may be negative.
+    ///
+    /// Returns `Err(ParseError::DateTooSmall)` or `Err(ParseError::DateTooLarge)` if the result would be
+    /// outside the supported range of `0000-01-01` to `9999-12-31`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let d = Date::parse_str("2022-12-31").unwrap();
+    /// assert_eq!(d.add_days(1).unwrap().to_string(), "2023-01-01");
+    /// assert_eq!(d.add_days(-365).unwrap().to_string(), "2021-12-31");
+    /// ```
+    pub fn add_days(&self, days: i64) -> Result<Self, ParseError> {
+        let seconds = days
+            .checked_mul(86_400)
+            .and_then(|seconds| seconds.checked_add(self.timestamp()));
+        match seconds {
+            Some(seconds) => Ok(Self::from_timestamp_calc(seconds)?.0),
+            None if days < 0 => Err(ParseError::DateTooSmall),
+            None => Err(ParseError::DateTooLarge),
+        }
+    }
+
+    /// Number of whole years from this date until `other`, e.g. an age in years where this is the date of
+    /// birth, negative if `other` is before this date.
+    ///
+    /// A year is only completed once the month and day of this date are reached, where the day doesn't exist
+    /// in that year it's limited to the end of the month as with [crate::DateTime::calendar_diff], so a
+    /// `02-29` anniversary is reached on `02-28` in common years.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// let birth = Date::parse_str("2000-03-15").unwrap();
+    /// assert_eq!(birth.completed_years_until(&Date::parse_str("2020-03-14").unwrap()), 19);
+    /// assert_eq!(birth.completed_years_until(&Date::parse_str("2020-03-15").unwrap()), 20);
+    /// ```
+    pub fn completed_years_until(&self, other: &Date) -> i32 {
+        if other < self {
+            return -other.completed_years_until(self);
+        }
+        let years = other.year as i32 - self.year as i32;
+        let anniversary_day = self.day.min(days_in_month(other.year, self.month).unwrap_or(31));
+        if (other.month, other.day) < (self.month, anniversary_day) {
+            years - 1
+        } else {
+            years
+        }
+    }
+
+    /// First day of the week containing this date, weeks start on Monday as in ISO 8601 or on Sunday
+    /// depending on `week_start`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::{Date, WeekStart};
+    ///
+    /// let d = Date::parse_str("2022-06-08").unwrap();
+    /// assert_eq!(d.start_of_week(WeekStart::Monday).unwrap().to_string(), "2022-06-06");
+    /// assert_eq!(d.start_of_week(WeekStart::Sunday).unwrap().to_string(), "2022-06-05");
+    /// ```
+    pub fn start_of_week(&self, week_start: WeekStart) -> Result<Self, ParseError> {
+        self.add_days(-(self.days_into_week(week_start) as i64))
+    }
+
+    /// Last day of the week containing this date, that is Sunday for weeks starting on Monday, or Saturday
+    /// for weeks starting on Sunday.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::{Date, WeekStart};
+    ///
+    /// let d = Date::parse_str("2022-06-08").unwrap();
+    /// assert_eq!(d.end_of_week(WeekStart::Monday).unwrap().to_string(), "2022-06-12");
+    /// assert_eq!(d.end_of_week(WeekStart::Sunday).unwrap().to_string(), "2022-06-11");
+    /// ```
+    pub fn end_of_week(&self, week_start: WeekStart) -> Result<Self, ParseError> {
+        self.add_days(6 - self.days_into_week(week_start) as i64)
+    }
+
+    /// Number of days since the start of the week
+    fn days_into_week(&self, week_start: WeekStart) -> u8 {
+        match week_start {
+            WeekStart::Monday => self.weekday(),
+            WeekStart::Sunday => (self.weekday() + 1) % 7,
+        }
+    }
+
+    /// Number of ISO 8601 weeks in `year`, either 52 or 53.
+    ///
+    /// A year has 53 weeks if it starts on a Thursday, or if it's a leap year starting on a Wednesday.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use speedate::Date;
+    ///
+    /// assert_eq!(Date::iso_weeks_in_year(2020), 53);
+    /// assert_eq!(Date::iso_weeks_in_year(2021), 52);
+    /// ```
+    pub fn iso_weeks_in_year(year: u16) -> u8 {
+        // 0000-01-01 was a Saturday, weekdays here are counted from Monday as 0
+        let days = year as i64 * 365 + intervening_leap_years(year as i64);
+        match (days + 5) % 7 {
+            3 => 53,
+            2 if is_leap_year(year) => 53,
+            _ => 52,
+        }
+    }
+
     pub(crate) fn timestamp_watershed(timestamp: i64) -> Result<(i64, u32), ParseError> {
+        Self::timestamp_with_unit(timestamp, TimestampUnit::Infer)
+    }
+
+    /// Split a timestamp in seconds or milliseconds into seconds and microseconds, `unit` decides which.
+    pub(crate) fn timestamp_with_unit(timestamp: i64, unit: TimestampUnit) -> Result<(i64, u32), ParseError> {
         let ts_abs = timestamp.checked_abs().ok_or(ParseError::DateTooSmall)?;
-        if ts_abs <= MS_WATERSHED {
+        if !unit.is_milliseconds(ts_abs as f64)? {
             return Ok((timestamp, 0));
         }
         let mut seconds = timestamp / 1_000;
@@ -327,39 +1091,101 @@ impl Date {
             let y4 = get_digit_unchecked!(bytes, 3, InvalidCharYear) as u16;
             year = y1 * 1000 + y2 * 100 + y3 * 10 + y4;
 
-            match bytes.get_unchecked(4) {
-                b'-' => (),
-                _ => return Err(ParseError::InvalidCharDateSep),
-            }
+            (month, day) = Self::parse_month_day_unchecked(bytes, 4)?;
+        }
 
-            let m1 = get_digit_unchecked!(bytes, 5, InvalidCharMonth);
-            let m2 = get_digit_unchecked!(bytes, 6, InvalidCharMonth);
-            month = m1 * 10 + m2;
+        Self::check_month_day(year, month, day)
+    }
 
-            match bytes.get_unchecked(7) {
-                b'-' => (),
-                _ => return Err(ParseError::InvalidCharDateSep),
-            }
+    /// Parse a date with a two digit year, e.g. `YY-MM-DD`, no check is performed for extra characters at
+    /// the end of the string.
+    ///
+    /// Years less than `pivot` are taken to be in the 21st century, other years are in the 20th century.
+    fn parse_bytes_partial_two_digit_year(bytes: &[u8], pivot: u16) -> Result<Self, ParseError> {
+        if bytes.len() < 8 {
+            return Err(ParseError::TooShort);
+        }
+        let year: u16;
+        let month: u8;
+        let day: u8;
+        unsafe {
+            let y1 = get_digit_unchecked!(bytes, 0, InvalidCharYear) as u16;
+            let y2 = get_digit_unchecked!(bytes, 1, InvalidCharYear) as u16;
+            let short_year = y1 * 10 + y2;
+            year = if short_year < pivot {
+                2000 + short_year
+            } else {
+                1900 + short_year
+            };
 
-            let d1 = get_digit_unchecked!(bytes, 8, InvalidCharDay);
-            let d2 = get_digit_unchecked!(bytes, 9, InvalidCharDay);
-            day = d1 * 10 + d2;
-        }
-
-        // calculate the maximum number of days in the month, accounting for leap years in the
-        // gregorian calendar
-        let max_days = match month {
-            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-            4 | 6 | 9 | 11 => 30,
-            2 => {
-                if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
-                    29
-                } else {
-                    28
-                }
-            }
-            _ => return Err(ParseError::OutOfRangeMonth),
+            (month, day) = Self::parse_month_day_unchecked(bytes, 2)?;
+        }
+
+        Self::check_month_day(year, month, day)
+    }
+
+    /// Parse a date with the year last, e.g. `DD/MM/YYYY` or `MM/DD/YYYY` depending on `order`, no check is
+    /// performed for extra characters at the end of the string.
+    ///
+    /// The separator may be `/`, `-` or `.` but must be the same in both positions.
+    fn parse_bytes_partial_year_last(bytes: &[u8], order: DateFieldOrder) -> Result<Self, ParseError> {
+        if bytes.len() < 10 {
+            return Err(ParseError::TooShort);
+        }
+        let (first_error, second_error) = match order {
+            DateFieldOrder::Mdy => (ParseError::InvalidCharMonth, ParseError::InvalidCharDay),
+            _ => (ParseError::InvalidCharDay, ParseError::InvalidCharMonth),
+        };
+        let two_digits = |index: usize, error: ParseError| match (bytes[index], bytes[index + 1]) {
+            (d1 @ b'0'..=b'9', d2 @ b'0'..=b'9') => Ok((d1 - b'0') * 10 + d2 - b'0'),
+            _ => Err(error),
         };
+        let first = two_digits(0, first_error)?;
+        let separator = bytes[2];
+        let second = two_digits(3, second_error)?;
+        if bytes[5] != separator {
+            return Err(ParseError::InvalidCharDateSep);
+        }
+        let year: u16;
+        unsafe {
+            let y1 = get_digit_unchecked!(bytes, 6, InvalidCharYear) as u16;
+            let y2 = get_digit_unchecked!(bytes, 7, InvalidCharYear) as u16;
+            let y3 = get_digit_unchecked!(bytes, 8, InvalidCharYear) as u16;
+            let y4 = get_digit_unchecked!(bytes, 9, InvalidCharYear) as u16;
+            year = y1 * 1000 + y2 * 100 + y3 * 10 + y4;
+        }
+
+        match order {
+            DateFieldOrder::Mdy => Self::check_month_day(year, first, second),
+            _ => Self::check_month_day(year, second, first),
+        }
+    }
+
+    /// Parse `-MM-DD` starting at `offset`, the caller must check `bytes` is at least `offset + 6` long.
+    #[inline(always)]
+    unsafe fn parse_month_day_unchecked(bytes: &[u8], offset: usize) -> Result<(u8, u8), ParseError> {
+        match bytes.get_unchecked(offset) {
+            b'-' => (),
+            _ => return Err(ParseError::InvalidCharDateSep),
+        }
+
+        let m1 = get_digit_unchecked!(bytes, offset + 1, InvalidCharMonth);
+        let m2 = get_digit_unchecked!(bytes, offset + 2, InvalidCharMonth);
+        let month = m1 * 10 + m2;
+
+        match bytes.get_unchecked(offset + 3) {
+            b'-' => (),
+            _ => return Err(ParseError::InvalidCharDateSep),
+        }
+
+        let d1 = get_digit_unchecked!(bytes, offset + 4, InvalidCharDay);
+        let d2 = get_digit_unchecked!(bytes, offset + 5, InvalidCharDay);
+        Ok((month, d1 * 10 + d2))
+    }
+
+    #[inline(always)]
+    pub(crate) fn check_month_day(year: u16, month: u8, day: u8) -> Result<Self, ParseError> {
+        let max_days = days_in_month(year, month).ok_or(ParseError::OutOfRangeMonth)?;
 
         if day < 1 || day > max_days {
             return Err(ParseError::OutOfRangeDay);
@@ -369,11 +1195,72 @@ impl Date {
     }
 }
 
+/// English month names, used when parsing months by name
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// Find the month number (1 to 12) from its English name, either in full or abbreviated to three letters,
+/// matching is case-insensitive.
+pub(crate) fn parse_month_name(name: &[u8]) -> Result<u8, ParseError> {
+    MONTH_NAMES
+        .iter()
+        .position(|full| {
+            let full = full.as_bytes();
+            (name.len() == 3 || name.len() == full.len()) && full[..name.len()].eq_ignore_ascii_case(name)
+        })
+        .map(|index| index as u8 + 1)
+        .ok_or(ParseError::InvalidMonthName)
+}
+
+/// Length of the run of ascii letters at the start of `bytes`
+fn month_name_len(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|c| c.is_ascii_alphabetic()).count()
+}
+
+/// Parse the four digit year at the start of `bytes`
+fn parse_year(bytes: &[u8]) -> Result<u16, ParseError> {
+    let y1 = get_digit!(bytes, 0, InvalidCharYear) as u16;
+    let y2 = get_digit!(bytes, 1, InvalidCharYear) as u16;
+    let y3 = get_digit!(bytes, 2, InvalidCharYear) as u16;
+    let y4 = get_digit!(bytes, 3, InvalidCharYear) as u16;
+    Ok(y1 * 1000 + y2 * 100 + y3 * 10 + y4)
+}
+
+/// The number of days in the month, accounting for leap years in the gregorian calendar, `None` if the
+/// month is not between 1 and 12
+#[inline(always)]
+pub(crate) fn days_in_month(year: u16, month: u8) -> Option<u8> {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+        4 | 6 | 9 | 11 => Some(30),
+        2 => {
+            if is_leap_year(year) {
+                Some(29)
+            } else {
+                Some(28)
+            }
+        }
+        _ => None,
+    }
+}
+
 fn is_leap_year(year: u16) -> bool {
-    if year % 100 == 0 {
-        year % 400 == 0
+    if year.is_multiple_of(100) {
+        year.is_multiple_of(400)
     } else {
-        year % 4 == 0
+        year.is_multiple_of(4)
     }
 }
 
@@ -420,3 +1307,131 @@ fn common_year_month_day(day: i16) -> (u8, u8) {
         _ => (12, (day - 334) as u8),
     }
 }
+
+/// The first day of the week, used by [Date::start_of_week] and [Date::end_of_week]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum WeekStart {
+    /// Weeks start on Monday, as in ISO 8601
+    #[default]
+    Monday,
+    /// Weeks start on Sunday
+    Sunday,
+}
+
+/// Order of the day, month and year fields in a date
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum DateFieldOrder {
+    /// `YYYY-MM-DD`
+    #[default]
+    Ymd,
+    /// `DD/MM/YYYY`, as commonly used in Europe
+    Dmy,
+    /// `MM/DD/YYYY`, as commonly used in the US
+    Mdy,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DateConfig {
+    /// When set, dates with a two digit year (`YY-MM-DD`) are accepted, years below the pivot are
+    /// interpreted as `20YY`, other years as `19YY`. This can't be combined with a `field_order` of `Dmy`
+    /// or `Mdy`, any date with a separator after the first two digits is then parsed with the year last.
+    pub two_digit_year_pivot: Option<u16>,
+    /// When `Dmy` or `Mdy`, dates with the year last (e.g. `DD/MM/YYYY`) are accepted with the day and month
+    /// in the given order, `YYYY-MM-DD` dates are still accepted
+    pub field_order: DateFieldOrder,
+    /// Accept a time after the date (e.g. `2020-01-01T00:00:00`) provided it's exactly midnight, the time is
+    /// discarded, other times are rejected with [ParseError::DateNotExact]
+    pub allow_time_suffix: bool,
+    /// When set, numeric inputs with fewer digits than this are rejected rather than being parsed as
+    /// unix timestamps, e.g. with `Some(5)` the input `0` is an error instead of `1970-01-01`
+    pub min_timestamp_digits: Option<usize>,
+    /// When set, inputs longer than this are rejected with [ParseError::InputTooLong] before any parsing, to
+    /// limit the work done on untrusted input.
+    pub max_input_len: Option<usize>,
+    /// Ignore leading and trailing ASCII whitespace when parsing, both for dates and unix timestamps.
+    pub trim_whitespace: bool,
+    /// Reject the year `0000` with [ParseError::YearZero], as required by RFC 3339, unix timestamps are
+    /// unaffected.
+    pub forbid_year_zero: bool,
+}
+
+impl DateConfig {
+    pub fn builder() -> DateConfigBuilder {
+        DateConfigBuilder::new()
+    }
+
+    /// Check `bytes` isn't longer than `max_input_len`.
+    #[inline]
+    pub(crate) fn check_input_len(&self, bytes: &[u8]) -> Result<(), ParseError> {
+        match self.max_input_len {
+            Some(max_input_len) if bytes.len() > max_input_len => Err(ParseError::InputTooLong),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove leading and trailing whitespace from `bytes` if `trim_whitespace` is set.
+    #[inline]
+    pub(crate) fn trim<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        if self.trim_whitespace {
+            bytes.trim_ascii()
+        } else {
+            bytes
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DateConfigBuilder {
+    two_digit_year_pivot: Option<u16>,
+    field_order: Option<DateFieldOrder>,
+    allow_time_suffix: bool,
+    min_timestamp_digits: Option<usize>,
+    max_input_len: Option<usize>,
+    trim_whitespace: bool,
+    forbid_year_zero: bool,
+}
+
+impl DateConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn two_digit_year_pivot(mut self, two_digit_year_pivot: Option<u16>) -> Self {
+        self.two_digit_year_pivot = two_digit_year_pivot;
+        self
+    }
+    pub fn field_order(mut self, field_order: DateFieldOrder) -> Self {
+        self.field_order = Some(field_order);
+        self
+    }
+    pub fn allow_time_suffix(mut self, allow_time_suffix: bool) -> Self {
+        self.allow_time_suffix = allow_time_suffix;
+        self
+    }
+    pub fn min_timestamp_digits(mut self, min_timestamp_digits: Option<usize>) -> Self {
+        self.min_timestamp_digits = min_timestamp_digits;
+        self
+    }
+    pub fn max_input_len(mut self, max_input_len: Option<usize>) -> Self {
+        self.max_input_len = max_input_len;
+        self
+    }
+    pub fn trim_whitespace(mut self, trim_whitespace: bool) -> Self {
+        self.trim_whitespace = trim_whitespace;
+        self
+    }
+    pub fn forbid_year_zero(mut self, forbid_year_zero: bool) -> Self {
+        self.forbid_year_zero = forbid_year_zero;
+        self
+    }
+    pub fn build(self) -> DateConfig {
+        DateConfig {
+            two_digit_year_pivot: self.two_digit_year_pivot,
+            field_order: self.field_order.unwrap_or_default(),
+            allow_time_suffix: self.allow_time_suffix,
+            min_timestamp_digits: self.min_timestamp_digits,
+            max_input_len: self.max_input_len,
+            trim_whitespace: self.trim_whitespace,
+            forbid_year_zero: self.forbid_year_zero,
+        }
+    }
+}
@@ -1,13 +1,23 @@
-use crate::date::MS_WATERSHED;
+use crate::date::days_in_month;
+use crate::numbers::{floor, round};
 use crate::{
     float_parse_bytes, numbers::decimal_digits, IntFloat, MicrosecondsPrecisionOverflowBehavior, TimeConfigBuilder,
 };
-use crate::{time::TimeConfig, Date, ParseError, Time};
-use std::cmp::Ordering;
-use std::fmt;
-use std::str::FromStr;
+use crate::{time::TimeConfig, Date, Duration, ParseError, Time};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Sub};
+use core::str::FromStr;
+#[cfg(feature = "std")]
 use std::time::SystemTime;
 
+/// Unix timestamp of the Apple Cocoa epoch, `2001-01-01T00:00:00`
+const COCOA_EPOCH: i64 = 978_307_200;
+/// Unix timestamp of the .NET epoch, `0001-01-01T00:00:00`
+const DOTNET_EPOCH: i64 = -62_135_596_800;
+
 /// A DateTime
 ///
 /// Combines a [Date], [Time].
@@ -24,7 +34,14 @@ use std::time::SystemTime;
 /// `DateTime` supports equality (`==`) and inequality (`>`, `<`, `>=`, `<=`) comparisons.
 ///
 /// See [DateTime::partial_cmp] for how this works.
-#[derive(Debug, PartialEq, Eq, Clone)]
+///
+/// A `DateTime` can also be compared to a [Date] with inequality comparisons in either order, the date is
+/// treated as midnight in the datetime's timezone, e.g. `2020-01-01T12:00:00` is greater than `2020-01-01`.
+///
+/// # Default
+///
+/// The default `DateTime` is the unix epoch without a timezone, `1970-01-01T00:00:00`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct DateTime {
     /// date part of the datetime
     pub date: Date,
@@ -32,6 +49,42 @@ pub struct DateTime {
     pub time: Time,
 }
 
+/// Where the timezone of a parsed [DateTime] came from, see [DateTime::parse_bytes_with_meta].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TzSource {
+    /// UTC given by `Z` or `z`, or a timezone name such as `UTC` when
+    /// [TimeConfig::allow_tz_names] is set
+    Zulu,
+    /// an explicit offset such as `+00:00`, or [TimeConfig::unix_timestamp_offset] for unix timestamps
+    Offset,
+    /// no timezone, `tz_offset` is `None`
+    Naive,
+}
+
+/// The difference between two datetimes in calendar units, see [DateTime::calendar_diff].
+///
+/// Unlike [Duration], which uses a fixed 365 days per year and 30 days per month, years and months here
+/// are calendar years and months, so their length depends on the dates.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CalendarDelta {
+    /// `false` if the first datetime is before the second
+    pub positive: bool,
+    /// whole years, 0 to 9999
+    pub years: u16,
+    /// whole months, 0 to 11
+    pub months: u8,
+    /// whole days, 0 to 30
+    pub days: u8,
+    /// hours, 0 to 23
+    pub hours: u8,
+    /// minutes, 0 to 59
+    pub minutes: u8,
+    /// seconds, 0 to 59
+    pub seconds: u8,
+    /// microseconds, 0 to 999_999
+    pub microseconds: u32,
+}
+
 impl fmt::Display for DateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.date)?;
@@ -52,6 +105,16 @@ impl FromStr for DateTime {
     }
 }
 
+impl TryFrom<i64> for DateTime {
+    type Error = ParseError;
+
+    /// Convert a unix timestamp in seconds or milliseconds to a datetime, see [DateTime::from_timestamp].
+    #[inline]
+    fn try_from(timestamp: i64) -> Result<Self, Self::Error> {
+        Self::from_timestamp(timestamp, 0)
+    }
+}
+
 impl PartialOrd for DateTime {
     /// Compare two datetimes by inequality.
     ///
@@ -134,7 +197,354 @@ impl PartialOrd for DateTime {
     }
 }
 
+impl From<Date> for DateTime {
+    /// Midnight at the start of the date without a timezone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, DateTime};
+    ///
+    /// let dt: DateTime = Date::parse_str("2022-01-01").unwrap().into();
+    /// assert_eq!(dt.to_string(), "2022-01-01T00:00:00");
+    /// ```
+    fn from(date: Date) -> Self {
+        Self {
+            date,
+            time: Time::default(),
+        }
+    }
+}
+
+impl From<DateTime> for Date {
+    /// The date of the datetime, the time and timezone are dropped.
+    fn from(datetime: DateTime) -> Self {
+        datetime.date
+    }
+}
+
+impl PartialEq<Date> for DateTime {
+    /// A datetime is equal to a date if it is exactly midnight on that date, the timezone offset is ignored.
+    fn eq(&self, other: &Date) -> bool {
+        self.date == *other
+            && self.time.hour == 0
+            && self.time.minute == 0
+            && self.time.second == 0
+            && self.time.microsecond == 0
+    }
+}
+
+impl PartialEq<DateTime> for Date {
+    fn eq(&self, other: &DateTime) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Date> for DateTime {
+    /// Compare a datetime to a date, the date is treated as midnight in the datetime's timezone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Date, DateTime};
+    ///
+    /// let d = Date::parse_str("2020-01-01").unwrap();
+    /// let dt = DateTime::parse_str("2020-01-01T12:00:00").unwrap();
+    ///
+    /// assert!(dt > d);
+    /// assert!(d < dt);
+    /// assert!(d == DateTime::parse_str("2020-01-01T00:00:00Z").unwrap());
+    /// ```
+    fn partial_cmp(&self, other: &Date) -> Option<Ordering> {
+        let midnight = Self {
+            date: other.clone(),
+            time: Time {
+                tz_offset: self.time.tz_offset,
+                ..Time::default()
+            },
+        };
+        self.partial_cmp(&midnight)
+    }
+}
+
+impl PartialOrd<DateTime> for Date {
+    /// Compare a date to a datetime, see [comparison](DateTime#comparison) for details.
+    fn partial_cmp(&self, other: &DateTime) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
+
+/// A [ParseError] along with the part of the input it relates to, as returned by [DateTime::parse_bytes_ctx].
+///
+/// Where the error relates to a single field, `offset` and `input` are the position and bytes of that field
+/// in the RFC 3339 layout `YYYY-MM-DDTHH:MM[:SS[.ffffff]]±HH:MM`, e.g. the two bytes of the minute for
+/// [ParseError::InvalidCharMinute], the whole timezone for timezone errors. For other errors, e.g.
+/// [ParseError::TooShort], `offset` is `0` and `input` is the whole input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseErrorWithInput<'a> {
+    /// The error from parsing
+    pub error: ParseError,
+    /// Byte offset of `input` within the input
+    pub offset: usize,
+    /// The part of the input the error relates to
+    pub input: &'a [u8],
+}
+
+impl<'a> ParseErrorWithInput<'a> {
+    fn new(error: ParseError, bytes: &'a [u8]) -> Self {
+        let (fraction_start, tz_start) = Self::fraction_tz_start(bytes);
+        let (start, end) = match error {
+            ParseError::InvalidCharYear => (0, 4),
+            ParseError::InvalidCharDateSep if bytes.get(4) == Some(&b'-') => (7, 8),
+            ParseError::InvalidCharDateSep => (4, 5),
+            ParseError::InvalidCharMonth | ParseError::OutOfRangeMonth => (5, 7),
+            ParseError::InvalidCharDay | ParseError::OutOfRangeDay => (8, 10),
+            ParseError::InvalidCharDateTimeSep | ParseError::MisplacedTimezone => (10, 11),
+            ParseError::InvalidCharHour | ParseError::OutOfRangeHour => (11, 13),
+            ParseError::InvalidCharTimeSep if bytes.get(13) == Some(&b':') => (16, 17),
+            ParseError::InvalidCharTimeSep => (13, 14),
+            ParseError::InvalidCharMinute | ParseError::OutOfRangeMinute => (14, 16),
+            ParseError::InvalidCharSecond | ParseError::OutOfRangeSecond => (17, 19),
+            ParseError::InvalidCharSecondFraction
+            | ParseError::SecondFractionTooLong
+            | ParseError::SecondFractionMissing => (fraction_start, tz_start),
+            ParseError::InvalidCharTzSign
+            | ParseError::InvalidCharTzHour
+            | ParseError::InvalidCharTzMinute
+            | ParseError::OutOfRangeTzMinute
+            | ParseError::InvalidCharTzSecond
+            | ParseError::OutOfRangeTzSecond
+            | ParseError::TzOffsetSecondsNotAllowed
+            | ParseError::OutOfRangeTz
+            | ParseError::DuplicateTimezone
+            | ParseError::UnknownTzName => (tz_start, bytes.len()),
+            _ => (0, bytes.len()),
+        };
+        let start = start.min(bytes.len());
+        let end = end.clamp(start, bytes.len());
+        Self {
+            error,
+            offset: start,
+            input: &bytes[start..end],
+        }
+    }
+
+    /// Positions where the fraction and the timezone start, the seconds are optional so the fraction starts
+    /// after either the minutes or the seconds, the timezone starts after the fraction if there is one.
+    fn fraction_tz_start(bytes: &[u8]) -> (usize, usize) {
+        let fraction_start = match bytes.get(16) {
+            Some(b':') => 19,
+            _ => 16,
+        };
+        let tz_start = match bytes.get(fraction_start) {
+            Some(b'.' | b',') => bytes[fraction_start + 1..]
+                .iter()
+                .position(|c| !c.is_ascii_digit())
+                .map_or(bytes.len(), |position| position + fraction_start + 1),
+            _ => fraction_start,
+        };
+        (fraction_start, tz_start)
+    }
+}
+
+impl fmt::Display for ParseErrorWithInput<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at byte {}: {:?}",
+            self.error,
+            self.offset,
+            String::from_utf8_lossy(self.input)
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseErrorWithInput<'_> {}
+
+/// A [DateTime] with an IANA timezone name such as `America/New_York`, as used in
+/// [RFC 9557](https://www.rfc-editor.org/rfc/rfc9557) timestamps like `2020-01-01T12:00:00-05:00[America/New_York]`.
+///
+/// The zone name is carried opaquely so it survives a round trip, no timezone database is used: the name is
+/// not checked against known zones and all offset calculations use the offset stored in `dt`.
+///
+/// # Examples
+///
+/// ```
+/// use speedate::ZonedDateTime;
+///
+/// let zdt = ZonedDateTime::parse_str("2020-01-01T12:00:00-05:00[America/New_York]").unwrap();
+/// assert_eq!(zdt.dt.time.tz_offset, Some(-5 * 3600));
+/// assert_eq!(zdt.zone_name, "America/New_York");
+/// assert_eq!(zdt.to_string(), "2020-01-01T12:00:00-05:00[America/New_York]");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ZonedDateTime {
+    /// the datetime, including its fixed offset
+    pub dt: DateTime,
+    /// IANA timezone name, e.g. `America/New_York`
+    pub zone_name: String,
+}
+
+impl fmt::Display for ZonedDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", self.dt, self.zone_name)
+    }
+}
+
+impl FromStr for ZonedDateTime {
+    type Err = ParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+impl ZonedDateTime {
+    /// Parse a datetime followed by a bracketed zone name, e.g. `2020-01-01T12:00:00-05:00[America/New_York]`.
+    ///
+    /// The datetime part is parsed with [DateTime::parse_bytes], the zone name may contain ascii letters,
+    /// digits, `/`, `_`, `-` and `+`.
+    ///
+    /// # Arguments
+    ///
+    /// * `str` - The string to parse
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{ParseError, ZonedDateTime};
+    ///
+    /// let zdt = ZonedDateTime::parse_str("2020-01-01T12:00:00Z[Europe/London]").unwrap();
+    /// assert_eq!(zdt.to_string(), "2020-01-01T12:00:00Z[Europe/London]");
+    ///
+    /// let error = ZonedDateTime::parse_str("2020-01-01T12:00:00Z").unwrap_err();
+    /// assert_eq!(error, ParseError::InvalidZoneName);
+    /// ```
+    pub fn parse_str(str: &str) -> Result<Self, ParseError> {
+        Self::parse_bytes(str.as_bytes())
+    }
+
+    /// As with [ZonedDateTime::parse_str] but with the input as bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::ZonedDateTime;
+    ///
+    /// let zdt = ZonedDateTime::parse_bytes(b"2020-01-01T12:00:00-05:00[America/New_York]").unwrap();
+    /// assert_eq!(zdt.zone_name, "America/New_York");
+    /// ```
+    pub fn parse_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        let (dt_bytes, zone_bytes) = match bytes.split_last() {
+            Some((b']', rest)) => match rest.iter().rposition(|&c| c == b'[') {
+                Some(open) => (&rest[..open], &rest[open + 1..]),
+                None => return Err(ParseError::InvalidZoneName),
+            },
+            _ => return Err(ParseError::InvalidZoneName),
+        };
+        let valid_char = |c: &u8| c.is_ascii_alphanumeric() || matches!(c, b'/' | b'_' | b'-' | b'+');
+        if zone_bytes.is_empty() || !zone_bytes.iter().all(valid_char) {
+            return Err(ParseError::InvalidZoneName);
+        }
+        let dt = DateTime::parse_bytes(dt_bytes)?;
+        // the zone name is all ascii, so this can't fail
+        let zone_name = String::from_utf8_lossy(zone_bytes).into_owned();
+        Ok(Self { dt, zone_name })
+    }
+}
+
+impl Add<&Duration> for DateTime {
+    type Output = Self;
+
+    /// Add a duration to the datetime, see [DateTime::add_duration].
+    ///
+    /// # Panics
+    ///
+    /// If the result would be outside the supported range of `0000-01-01` to `9999-12-31`, use
+    /// [DateTime::add_duration] to handle this case.
+    fn add(self, duration: &Duration) -> Self {
+        match self.add_duration(duration) {
+            Ok(dt) => dt,
+            Err(e) => panic!("datetime out of range: {e}"),
+        }
+    }
+}
+
+impl Sub<&Duration> for DateTime {
+    type Output = Self;
+
+    /// Subtract a duration from the datetime, see [DateTime::sub_duration].
+    ///
+    /// # Panics
+    ///
+    /// If the result would be outside the supported range of `0000-01-01` to `9999-12-31`, use
+    /// [DateTime::sub_duration] to handle this case.
+    fn sub(self, duration: &Duration) -> Self {
+        match self.sub_duration(duration) {
+            Ok(dt) => dt,
+            Err(e) => panic!("datetime out of range: {e}"),
+        }
+    }
+}
+
+impl Sub<&DateTime> for &DateTime {
+    type Output = Duration;
+
+    /// The duration from `other` to `self`, negative if `other` is after `self`.
+    ///
+    /// As with comparison, if both datetimes have a timezone offset the difference between the instants is
+    /// returned, otherwise the local dates and times are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt1 = DateTime::parse_str("2022-01-02T12:00:00+01:00").unwrap();
+    /// let dt2 = DateTime::parse_str("2022-01-01T00:00:00Z").unwrap();
+    /// assert_eq!((&dt1 - &dt2).to_string(), "P1DT11H");
+    /// ```
+    fn sub(self, other: &DateTime) -> Duration {
+        let diff = match (self.time.tz_offset, other.time.tz_offset) {
+            (Some(_), Some(_)) => {
+                (self.timestamp_tz() as i128 - other.timestamp_tz() as i128) * 1_000_000
+                    + (self.time.microsecond as i128 - other.time.microsecond as i128)
+            }
+            _ => self.timestamp_microseconds() - other.timestamp_microseconds(),
+        };
+        let abs_diff = diff.unsigned_abs();
+        // at most 10,000 years, so this is always within range
+        Duration::new(
+            diff >= 0,
+            (abs_diff / 86_400_000_000) as u32,
+            (abs_diff / 1_000_000 % 86_400) as u32,
+            (abs_diff % 1_000_000) as u32,
+        )
+        .unwrap()
+    }
+}
+
 impl DateTime {
+    /// The earliest supported datetime, `0000-01-01T00:00:00` with no timezone offset,
+    /// this matches the lower bound of [DateTime::from_timestamp].
+    pub const MIN: Self = Self {
+        date: Date::MIN,
+        time: Time::MIN,
+    };
+
+    /// The latest supported datetime, `9999-12-31T23:59:59.999999` with no timezone offset,
+    /// this matches the upper bound of [DateTime::from_timestamp].
+    pub const MAX: Self = Self {
+        date: Date::MAX,
+        time: Time::MAX,
+    };
+
     /// Parse a datetime from a string
     ///
     /// # Arguments
@@ -219,6 +629,31 @@ impl DateTime {
     pub fn parse_str(str: &str) -> Result<Self, ParseError> {
         Self::parse_bytes(str.as_bytes())
     }
+
+    /// Parse many datetimes using [DateTime::parse_str], errors are tagged with the index of the input
+    /// which failed.
+    ///
+    /// # Arguments
+    ///
+    /// * `inputs` - The strings to parse
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, ParseError};
+    ///
+    /// let results = DateTime::parse_many(["2022-01-01T12:13:14Z", "foobar"]);
+    /// assert_eq!(results[0].as_ref().unwrap().to_string(), "2022-01-01T12:13:14Z");
+    /// assert_eq!(results[1], Err((1, ParseError::TooShort)));
+    /// ```
+    pub fn parse_many<'a>(inputs: impl IntoIterator<Item = &'a str>) -> Vec<Result<Self, (usize, ParseError)>> {
+        inputs
+            .into_iter()
+            .enumerate()
+            .map(|(index, input)| Self::parse_str(input).map_err(|e| (index, e)))
+            .collect()
+    }
+
     /// Parse a datetime from bytes using RFC 3339 format
     ///
     /// # Arguments
@@ -287,21 +722,147 @@ impl DateTime {
     /// assert_eq!(dt.to_string(), "2022-01-01T12:13:14Z");
     /// ```
     pub fn parse_bytes_rfc3339_with_config(bytes: &[u8], config: &TimeConfig) -> Result<Self, ParseError> {
+        config.check_input_len(bytes)?;
+        let bytes = config.trim(bytes);
+        if let Some(dt) = Self::parse_bytes_fixed_utc(bytes) {
+            return Ok(dt);
+        }
+
         // First up, parse the full date if we can
-        let date = Date::parse_bytes_partial(bytes)?;
+        let mut date = Date::parse_bytes_partial(bytes)?;
 
         // Next parse the separator between date and time
         let sep = bytes.get(10).copied();
-        if sep != Some(b'T') && sep != Some(b't') && sep != Some(b' ') && sep != Some(b'_') {
-            return Err(ParseError::InvalidCharDateTimeSep);
-        }
+        let time_offset = match sep {
+            Some(b'T' | b't' | b' ' | b'_') => 11,
+            // e.g. `2020-01-0112:13:14`, the time starts straight after the date
+            Some(c) if c.is_ascii_digit() && config.allow_missing_datetime_sep => 10,
+            // e.g. `2020-01-01Z12:13:14`
+            Some(b'Z' | b'z') if bytes.len() > 11 => return Err(ParseError::MisplacedTimezone),
+            _ => return Err(ParseError::InvalidCharDateTimeSep),
+        };
 
         // Next try to parse the time
-        let time = Time::parse_bytes_offset(bytes, 11, config)?;
+        let (time, end_of_day) = Time::parse_bytes_offset_end_of_day(bytes, time_offset, config)?;
+        if end_of_day {
+            // `24:00:00` is midnight at the start of the next day
+            (date, _) = Date::from_timestamp_calc(date.timestamp() + 86_400)?;
+        }
 
         Ok(Self { date, time })
     }
 
+    /// Same as `DateTime::parse_bytes_rfc3339_with_config` but also returns the nanoseconds beyond microsecond
+    /// precision, e.g. `789` for `12:13:14.123456789`.
+    ///
+    /// The 7th to 9th digits of the second fraction are always accepted,
+    /// [TimeConfig::microseconds_precision_overflow_behavior] only applies to digits beyond the 9th. The
+    /// microseconds of the returned datetime are truncated so they can be combined with the nanoseconds.
+    /// A fraction of a minute, see [TimeConfig::allow_fractional_minutes], is parsed as normal with no
+    /// nanoseconds.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    /// * `config` - The `TimeConfig` to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, TimeConfigBuilder};
+    ///
+    /// let config = TimeConfigBuilder::new().build();
+    /// let (dt, nanosecond) = DateTime::parse_bytes_nanos(b"2022-01-01T12:13:14.123456789Z", &config).unwrap();
+    /// assert_eq!(dt.to_string(), "2022-01-01T12:13:14.123456Z");
+    /// assert_eq!(nanosecond, 789);
+    /// ```
+    pub fn parse_bytes_nanos(bytes: &[u8], config: &TimeConfig) -> Result<(Self, u32), ParseError> {
+        config.check_input_len(bytes)?;
+        let trimmed = config.trim(bytes);
+        // the second fraction is the first `.` or `,` after the date, provided it follows the seconds rather
+        // than being a fraction of the minute, e.g. `12:13.5` with `allow_fractional_minutes`
+        let Some(fraction_start) = trimmed
+            .iter()
+            .skip(10)
+            .position(|&c| c == b'.' || c == b',')
+            .map(|position| position + 11)
+            .filter(|&fraction_start| Self::ends_with_seconds(&trimmed[10..fraction_start - 1]))
+        else {
+            return Ok((Self::parse_bytes_rfc3339_with_config(bytes, config)?, 0));
+        };
+
+        let digits = trimmed[fraction_start..]
+            .iter()
+            .take_while(|c| c.is_ascii_digit())
+            .count();
+        if digits > 9 && config.microseconds_precision_overflow_behavior == MicrosecondsPrecisionOverflowBehavior::Error
+        {
+            return Err(ParseError::SecondFractionTooLong);
+        }
+        let mut nanosecond = 0;
+        for i in 6..9 {
+            nanosecond *= 10;
+            if i < digits {
+                nanosecond += (trimmed[fraction_start + i] - b'0') as u32;
+            }
+        }
+
+        let mut truncate_config = config.clone();
+        truncate_config.microseconds_precision_overflow_behavior = MicrosecondsPrecisionOverflowBehavior::Truncate;
+        let dt = Self::parse_bytes_rfc3339_with_config(bytes, &truncate_config)?;
+        Ok((dt, nanosecond))
+    }
+
+    /// Whether `time`, the bytes between the date and a fraction, ends with seconds, either as `HH:MM:SS` or
+    /// in basic format as `HHMMSS`, the date time separator may be included.
+    fn ends_with_seconds(time: &[u8]) -> bool {
+        let time = match time {
+            [b'0'..=b'9', ..] => time,
+            [_, rest @ ..] => rest,
+            [] => time,
+        };
+        time.iter().filter(|&&c| c == b':').count() == 2 || (time.len() == 6 && time.iter().all(u8::is_ascii_digit))
+    }
+
+    /// Fast path for the very common `YYYY-MM-DDTHH:MM:SSZ` format.
+    ///
+    /// All digits are validated at once, then fields are parsed without further checks. `None` is returned
+    /// if the input isn't exactly in this format or isn't valid, in which case the input should be parsed as
+    /// normal, this means results and errors are always identical to the normal path.
+    #[inline]
+    fn parse_bytes_fixed_utc(bytes: &[u8]) -> Option<Self> {
+        let bytes: &[u8; 20] = bytes.try_into().ok()?;
+        // the three 8 byte chunks overlap, between them they cover all 20 bytes
+        for (chunk_start, digits_mask, separators) in FIXED_UTC_CHUNKS {
+            let chunk = u64::from_le_bytes(bytes[chunk_start..chunk_start + 8].try_into().unwrap());
+            if chunk & !digits_mask != separators || !all_digits(chunk & digits_mask | ASCII_ZEROS & !digits_mask) {
+                return None;
+            }
+        }
+
+        let digit = |index: usize| bytes[index] - b'0';
+        let year = digit(0) as u16 * 1000 + digit(1) as u16 * 100 + digit(2) as u16 * 10 + digit(3) as u16;
+        let month = digit(5) * 10 + digit(6);
+        let day = digit(8) * 10 + digit(9);
+        let hour = digit(11) * 10 + digit(12);
+        let minute = digit(14) * 10 + digit(15);
+        let second = digit(17) * 10 + digit(18);
+        if hour > 23 || minute > 59 || second > 59 {
+            return None;
+        }
+        let date = Date::check_month_day(year, month, day).ok()?;
+        Some(Self {
+            date,
+            time: Time {
+                hour,
+                minute,
+                second,
+                microsecond: 0,
+                tz_offset: Some(0),
+            },
+        })
+    }
+
     /// As with [DateTime::parse_bytes_rfc3339] but also supports unix timestamps.
     ///
     /// # Arguments
@@ -323,8 +884,36 @@ impl DateTime {
         DateTime::parse_bytes_with_config(bytes, &TimeConfigBuilder::new().build())
     }
 
+    /// As with [DateTime::parse_bytes_rfc3339], but errors include the part of the input they relate to, see
+    /// [ParseErrorWithInput]. Unix timestamps aren't accepted since errors are located using the RFC 3339
+    /// layout.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, ParseError};
+    ///
+    /// let error = DateTime::parse_bytes_ctx(b"2022-01-01T12:61:14Z").unwrap_err();
+    /// assert_eq!(error.error, ParseError::OutOfRangeMinute);
+    /// assert_eq!(error.offset, 14);
+    /// assert_eq!(error.input, b"61");
+    /// ```
+    pub fn parse_bytes_ctx(bytes: &[u8]) -> Result<Self, ParseErrorWithInput<'_>> {
+        Self::parse_bytes_rfc3339(bytes).map_err(|error| ParseErrorWithInput::new(error, bytes))
+    }
+
     /// Same as `DateTime::parse_bytes` but supporting TimeConfig
     ///
+    /// # Fractional timestamps
+    ///
+    /// Timestamps are interpreted as seconds or milliseconds as described in [DateTime::from_timestamp].
+    /// The fraction of a timestamp in seconds gives the microseconds, up to 6 decimal places. For a timestamp
+    /// in milliseconds the fraction is a fraction of a millisecond, so up to 3 decimal places are kept as
+    /// microseconds rather than being dropped, e.g. `1711445175471.865` is `2024-03-26T09:26:15.471865`.
+    /// More decimal places give [ParseError::SecondFractionTooLong] or [ParseError::MillisecondFractionTooLong]
+    /// respectively unless [TimeConfig::microseconds_precision_overflow_behavior] is not `Error`, in which case
+    /// the microseconds are rounded according to [TimeConfig::float_rounding].
+    ///
     /// # Arguments
     ///
     /// * `bytes` - The bytes to parse
@@ -339,59 +928,118 @@ impl DateTime {
     /// assert_eq!(dt.to_string(), "2022-01-01T12:13:14Z");
     /// ```
     pub fn parse_bytes_with_config(bytes: &[u8], config: &TimeConfig) -> Result<Self, ParseError> {
+        config.check_input_len(bytes)?;
         match Self::parse_bytes_rfc3339_with_config(bytes, config) {
             Ok(d) => Ok(d),
-            Err(e) => match float_parse_bytes(bytes) {
-                IntFloat::Int(int) => Self::from_timestamp_with_config(int, 0, config),
-                IntFloat::Float(float) => {
-                    let timestamp_in_milliseconds = float.abs() > MS_WATERSHED as f64;
-
-                    if config.microseconds_precision_overflow_behavior == MicrosecondsPrecisionOverflowBehavior::Error {
-                        let decimal_digits_count = decimal_digits(bytes);
-
-                        // If the number of decimal digits exceeds the maximum allowed for the timestamp precision,
-                        // return an error. For timestamps in milliseconds, the maximum is 3, for timestamps in seconds,
-                        // the maximum is 6. These end up being the same in terms of allowing microsecond precision.
-                        if timestamp_in_milliseconds && decimal_digits_count > 3 {
-                            return Err(ParseError::MillisecondFractionTooLong);
-                        } else if !timestamp_in_milliseconds && decimal_digits_count > 6 {
-                            return Err(ParseError::SecondFractionTooLong);
-                        }
-                    }
+            Err(e) => {
+                // e.g. `@1654646400`
+                let bytes = match config.trim(bytes) {
+                    [b'@', timestamp @ ..] if config.allow_timestamp_at_prefix => timestamp,
+                    bytes => bytes,
+                };
+                if crate::is_relative_keyword(bytes) {
+                    return Err(ParseError::RelativeNotSupported);
+                }
+                Self::parse_timestamp_bytes(bytes, config, e)
+            }
+        }
+    }
 
-                    let timestamp_normalized: f64 = if timestamp_in_milliseconds {
-                        float / 1_000f64
-                    } else {
-                        float
-                    };
+    /// Parse `bytes` as a unix timestamp in seconds or milliseconds, `error` is returned if it's not a number.
+    fn parse_timestamp_bytes(bytes: &[u8], config: &TimeConfig, error: ParseError) -> Result<Self, ParseError> {
+        match float_parse_bytes(bytes) {
+            IntFloat::Int(int) => Self::from_timestamp_with_config(int, 0, config),
+            IntFloat::Float(float) => {
+                let timestamp_in_milliseconds = config.timestamp_unit.is_milliseconds(float.abs())?;
 
-                    // if seconds is negative, we round down (left on the number line), so -6.25 -> -7
-                    // which allows for a positive number of microseconds to compensate back up to -6.25
-                    // which is the equivalent of doing (seconds - 1) and (microseconds + 1_000_000)
-                    // like we do in Date::timestamp_watershed
-                    let seconds = timestamp_normalized.floor() as i64;
-                    let microseconds = ((timestamp_normalized - seconds as f64) * 1_000_000f64).round() as u32;
+                if config.microseconds_precision_overflow_behavior == MicrosecondsPrecisionOverflowBehavior::Error {
+                    let decimal_digits_count = decimal_digits(bytes);
 
-                    Self::from_timestamp_with_config(seconds, microseconds, config)
+                    // If the number of decimal digits exceeds the maximum allowed for the timestamp precision,
+                    // return an error. For timestamps in milliseconds, the maximum is 3, for timestamps in seconds,
+                    // the maximum is 6. These end up being the same in terms of allowing microsecond precision.
+                    if timestamp_in_milliseconds && decimal_digits_count > 3 {
+                        return Err(ParseError::MillisecondFractionTooLong);
+                    } else if !timestamp_in_milliseconds && decimal_digits_count > 6 {
+                        return Err(ParseError::SecondFractionTooLong);
+                    }
                 }
-                IntFloat::Err => Err(e),
-            },
+
+                let timestamp_normalized: f64 = if timestamp_in_milliseconds {
+                    float / 1_000f64
+                } else {
+                    float
+                };
+
+                // if seconds is negative, we round down (left on the number line), so -6.25 -> -7
+                // which allows for a positive number of microseconds to compensate back up to -6.25
+                // which is the equivalent of doing (seconds - 1) and (microseconds + 1_000_000)
+                // like we do in Date::timestamp_watershed
+                let mut seconds = floor(timestamp_normalized) as i64;
+                let mut microseconds = config
+                    .float_rounding
+                    .apply((timestamp_normalized - seconds as f64) * 1_000_000f64)
+                    as u32;
+                // rounding a fraction such as `.9999999` up gives a whole second, carry it into the seconds
+                if microseconds >= 1_000_000 {
+                    seconds += 1;
+                    microseconds -= 1_000_000;
+                }
+
+                Self::from_timestamp_with_config(seconds, microseconds, config)
+            }
+            IntFloat::Err => Err(error),
         }
     }
 
+    /// Same as `DateTime::parse_bytes_with_config` but also returns where the timezone came from.
+    ///
+    /// This distinguishes `Z` from `+00:00`, both of which are parsed as `tz_offset: Some(0)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    /// * `config` - The TimeConfig to use when parsing the time portion
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, TimeConfigBuilder, TzSource};
+    ///
+    /// let config = TimeConfigBuilder::new().build();
+    /// let (dt, source) = DateTime::parse_bytes_with_meta(b"2022-01-01T12:13:14Z", &config).unwrap();
+    /// assert_eq!(dt.time.tz_offset, Some(0));
+    /// assert_eq!(source, TzSource::Zulu);
+    ///
+    /// let (dt, source) = DateTime::parse_bytes_with_meta(b"2022-01-01T12:13:14+00:00", &config).unwrap();
+    /// assert_eq!(dt.time.tz_offset, Some(0));
+    /// assert_eq!(source, TzSource::Offset);
+    /// ```
+    pub fn parse_bytes_with_meta(bytes: &[u8], config: &TimeConfig) -> Result<(Self, TzSource), ParseError> {
+        let dt = Self::parse_bytes_with_config(bytes, config)?;
+        // the timezone is always at the end of the input, offsets end with a digit while `Z` and names end
+        // with a letter
+        let source = match dt.time.tz_offset {
+            None => TzSource::Naive,
+            Some(_) if config.trim(bytes).last().is_some_and(u8::is_ascii_alphabetic) => TzSource::Zulu,
+            Some(_) => TzSource::Offset,
+        };
+        Ok((dt, source))
+    }
+
     /// Like `from_timestamp` but with a `TimeConfig`.
     ///
     /// ("Unix Timestamp" means number of seconds or milliseconds since 1970-01-01)
     ///
-    /// Input must be between `-11_676_096_000` (`1600-01-01T00:00:00`) and
-    /// `253_402_300_799_000` (`9999-12-31T23:59:59.999999`) inclusive.
+    /// Input must be between `-62_167_219_200_000` ([DateTime::MIN], `0000-01-01T00:00:00`) and
+    /// `253_402_300_799_999` ([DateTime::MAX] to the millisecond, `9999-12-31T23:59:59.999`) inclusive.
     ///
     /// If the absolute value is > 2e10 (`20_000_000_000`) it is interpreted as being in milliseconds.
     ///
     /// That means:
     /// * `20_000_000_000` is `2603-10-11T11:33:20`
     /// * `20_000_000_001` is `1970-08-20T11:33:20.001`
-    /// * `-20_000_000_000` gives an error - `DateTooSmall` as it would be before 1600
+    /// * `-20_000_000_000` is `1336-03-23T12:26:40`
     /// * `-20_000_000_001` is `1969-05-14T12:26:39.999`
     ///
     /// # Arguments
@@ -419,7 +1067,7 @@ impl DateTime {
         timestamp_microsecond: u32,
         config: &TimeConfig,
     ) -> Result<Self, ParseError> {
-        let (mut second, extra_microsecond) = Date::timestamp_watershed(timestamp)?;
+        let (mut second, extra_microsecond) = Date::timestamp_with_unit(timestamp, config.timestamp_unit)?;
         let mut total_microsecond = timestamp_microsecond
             .checked_add(extra_microsecond)
             .ok_or(ParseError::TimeTooLarge)?;
@@ -459,6 +1107,13 @@ impl DateTime {
     /// Where `timestamp` is interrupted as milliseconds and is not a whole second, the remainder is added to
     /// `timestamp_microsecond`.
     ///
+    /// # Round trip
+    ///
+    /// For naive datetimes between `1336-03-23T12:26:40` and `2603-10-11T11:33:20` inclusive,
+    /// `DateTime::from_timestamp(dt.timestamp(), dt.time.microsecond)` is guaranteed to reproduce `dt`,
+    /// including for negative timestamps with a microsecond fraction. Outside that range, `dt.timestamp()`
+    /// would be interpreted as milliseconds, so `dt.timestamp() * 1000` should be passed instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -469,11 +1124,72 @@ impl DateTime {
     ///
     /// let d = DateTime::from_timestamp(1_654_619_320_123, 123_000).unwrap();
     /// assert_eq!(d.to_string(), "2022-06-07T16:28:40.246000");
+    ///
+    /// let dt = DateTime::parse_str("1969-12-31T23:59:59.999999").unwrap();
+    /// assert_eq!(DateTime::from_timestamp(dt.timestamp(), dt.time.microsecond).unwrap(), dt);
     /// ```
     pub fn from_timestamp(timestamp: i64, timestamp_microsecond: u32) -> Result<Self, ParseError> {
         Self::from_timestamp_with_config(timestamp, timestamp_microsecond, &TimeConfigBuilder::new().build())
     }
 
+    /// Create a datetime from an Apple Cocoa timestamp, the number of seconds since `2001-01-01T00:00:00`
+    /// as used by `NSDate` and Core Data.
+    ///
+    /// Unlike [DateTime::from_timestamp], `timestamp` is always in seconds, the fraction is rounded to the
+    /// nearest microsecond. The result is naive, `NaN` returns `Err(ParseError::DateTooLarge)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let d = DateTime::from_cocoa_timestamp(662_688_000.5).unwrap();
+    /// assert_eq!(d.to_string(), "2022-01-01T00:00:00.500000");
+    /// ```
+    pub fn from_cocoa_timestamp(timestamp: f64) -> Result<Self, ParseError> {
+        if timestamp.is_nan() {
+            return Err(ParseError::DateTooLarge);
+        }
+        let whole_seconds = floor(timestamp);
+        let mut microsecond = round((timestamp - whole_seconds) * 1_000_000f64) as u32;
+        // out of range values saturate, then give an error below
+        let mut second = (whole_seconds as i64).saturating_add(COCOA_EPOCH);
+        if microsecond >= 1_000_000 {
+            second = second.saturating_add(1);
+            microsecond -= 1_000_000;
+        }
+        Self::from_unix_second_microsecond(second, microsecond)
+    }
+
+    /// Create a datetime from .NET ticks, the number of 100 nanosecond intervals since `0001-01-01T00:00:00`
+    /// as used by `DateTime.Ticks`.
+    ///
+    /// Ticks are truncated to whole microseconds, the result is naive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let d = DateTime::from_dotnet_ticks(637_134_336_001_234_567).unwrap();
+    /// assert_eq!(d.to_string(), "2020-01-01T00:00:00.123456");
+    /// ```
+    pub fn from_dotnet_ticks(ticks: i64) -> Result<Self, ParseError> {
+        let second = ticks.div_euclid(10_000_000) + DOTNET_EPOCH;
+        let microsecond = (ticks.rem_euclid(10_000_000) / 10) as u32;
+        Self::from_unix_second_microsecond(second, microsecond)
+    }
+
+    /// Create a naive datetime from a unix timestamp which is always in seconds, `microsecond` must be
+    /// less than `1_000_000`.
+    fn from_unix_second_microsecond(second: i64, microsecond: u32) -> Result<Self, ParseError> {
+        let (date, time_second) = Date::from_timestamp_calc(second)?;
+        Ok(Self {
+            date,
+            time: Time::from_timestamp(time_second, microsecond)?,
+        })
+    }
+
     /// Create a datetime from the system time. This method uses [std::time::SystemTime] to get
     /// the system time and uses it to create a [DateTime] adjusted to the specified timezone offset.
     ///
@@ -489,6 +1205,7 @@ impl DateTime {
     /// let now = DateTime::now(0).unwrap();
     /// println!("Current date and time: {}", now);
     /// ```
+    #[cfg(feature = "std")]
     pub fn now(tz_offset: i32) -> Result<Self, ParseError> {
         let t = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
@@ -502,6 +1219,72 @@ impl DateTime {
         }
     }
 
+    /// Parse a datetime read from `reader`, as with [DateTime::parse_bytes] both RFC 3339 datetimes and unix
+    /// timestamps are supported.
+    ///
+    /// At most 35 bytes are read into a buffer, one more than the longest valid input
+    /// `YYYY-MM-DDTHH:MM:SS.ffffff−HH:MM` (using the three byte U+2212 minus), longer inputs are rejected with
+    /// [ParseError::InputTooLong] and any bytes beyond those read are left in `reader`. The whole of the
+    /// input must be a datetime, trailing bytes after it give the same errors as [DateTime::parse_bytes],
+    /// e.g. [ParseError::ExtraCharacters]. Errors from `reader` are returned as [ParseError::ReadError].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_reader(&mut Cursor::new(b"2022-01-01T12:13:14Z")).unwrap();
+    /// assert_eq!(dt.to_string(), "2022-01-01T12:13:14Z");
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_reader<R: std::io::Read>(reader: &mut R) -> Result<Self, ParseError> {
+        Self::parse_bytes(&crate::read_bounded(reader, 34)?)
+    }
+
+    /// Description of the format produced by `to_string()`, see [Date::format_hint] and [Time::format_hint].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// assert_eq!(DateTime::format_hint(), "%Y-%m-%dT%H:%M:%S[.%f][Z|±HH:MM]");
+    /// ```
+    pub const fn format_hint() -> &'static str {
+        "%Y-%m-%dT%H:%M:%S[.%f][Z|±HH:MM]"
+    }
+
+    /// Like `to_string()`, but a zero timezone offset is rendered as `+00:00` rather than `Z`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:13:14Z").unwrap();
+    /// assert_eq!(dt.to_string(), "2022-01-01T12:13:14Z");
+    /// assert_eq!(dt.to_string_z_as_offset(), "2022-01-01T12:13:14+00:00");
+    /// ```
+    pub fn to_string_z_as_offset(&self) -> String {
+        alloc::format!("{}T{}", self.date, self.time.to_string_z_as_offset())
+    }
+
+    /// Like `to_string()`, but trailing zeros are removed from the second fraction, see [Time::to_string_trimmed].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:13:14.123+01:00").unwrap();
+    /// assert_eq!(dt.to_string(), "2022-01-01T12:13:14.123000+01:00");
+    /// assert_eq!(dt.to_string_trimmed(), "2022-01-01T12:13:14.123+01:00");
+    /// ```
+    pub fn to_string_trimmed(&self) -> String {
+        alloc::format!("{}T{}", self.date, self.time.to_string_trimmed())
+    }
+
     /// Clone the datetime and set a new timezone offset.
     ///
     /// The returned datetime will represent a different point in time since the timezone offset is changed without
@@ -530,6 +1313,56 @@ impl DateTime {
         })
     }
 
+    /// Clone the datetime and set a new hour, see [Time::with_hour].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:13:14Z").unwrap();
+    /// assert_eq!(dt.with_hour(23).unwrap().to_string(), "2022-01-01T23:13:14Z");
+    /// ```
+    pub fn with_hour(&self, hour: u8) -> Result<Self, ParseError> {
+        Ok(Self {
+            date: self.date.clone(),
+            time: self.time.with_hour(hour)?,
+        })
+    }
+
+    /// Clone the datetime and set a new minute, see [Time::with_minute].
+    pub fn with_minute(&self, minute: u8) -> Result<Self, ParseError> {
+        Ok(Self {
+            date: self.date.clone(),
+            time: self.time.with_minute(minute)?,
+        })
+    }
+
+    /// Clone the datetime and set a new second, see [Time::with_second].
+    pub fn with_second(&self, second: u8) -> Result<Self, ParseError> {
+        Ok(Self {
+            date: self.date.clone(),
+            time: self.time.with_second(second)?,
+        })
+    }
+
+    /// Clone the datetime and set a new microsecond, see [Time::with_microsecond].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:13:14.123456Z").unwrap();
+    /// assert_eq!(dt.with_microsecond(500_000).unwrap().to_string(), "2022-01-01T12:13:14.500000Z");
+    /// ```
+    pub fn with_microsecond(&self, microsecond: u32) -> Result<Self, ParseError> {
+        Ok(Self {
+            date: self.date.clone(),
+            time: self.time.with_microsecond(microsecond)?,
+        })
+    }
+
     /// Create a new datetime in a different timezone with date & time adjusted to represent the same moment in time.
     /// See [DateTime::with_timezone_offset] for alternative behaviour.
     ///
@@ -562,6 +1395,266 @@ impl DateTime {
         }
     }
 
+    /// Add a duration to the datetime, the timezone offset is unchanged.
+    ///
+    /// Returns `Err(ParseError::DateTooSmall)` or `Err(ParseError::DateTooLarge)` if the result would be
+    /// outside the supported range of `0000-01-01` to `9999-12-31`.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - the duration to add, may be negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:13:14Z").unwrap();
+    /// let d = Duration::parse_str("P1DT1.5S").unwrap();
+    /// assert_eq!(dt.add_duration(&d).unwrap().to_string(), "2022-01-02T12:13:15.500000Z");
+    /// ```
+    pub fn add_duration(&self, duration: &Duration) -> Result<Self, ParseError> {
+        let mut second = self.timestamp() + duration.signed_total_seconds();
+        let mut microsecond = self.time.microsecond as i64 + duration.signed_microseconds() as i64;
+        if microsecond < 0 {
+            second -= 1;
+            microsecond += 1_000_000;
+        } else if microsecond >= 1_000_000 {
+            second += 1;
+            microsecond -= 1_000_000;
+        }
+        let (date, time_second) = Date::from_timestamp_calc(second)?;
+        let mut time = Time::from_timestamp(time_second, microsecond as u32)?;
+        time.tz_offset = self.time.tz_offset;
+        Ok(Self { date, time })
+    }
+
+    /// Round the datetime to the nearest multiple of `step`, ties are rounded up, the timezone offset is unchanged.
+    ///
+    /// Multiples are counted from `1970-01-01T00:00:00` in the datetime's own timezone, so rounding to
+    /// `PT1H` or `P1D` gives whole hours or days in local time. The sign of `step` is ignored.
+    ///
+    /// Returns `Err(ParseError::DurationStepZero)` if `step` is zero, or `Err(ParseError::DateTooSmall)` or
+    /// `Err(ParseError::DateTooLarge)` if the result would be outside the supported range.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - the duration to round to a multiple of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:07:29+01:00").unwrap();
+    /// let step = Duration::parse_str("PT15M").unwrap();
+    /// assert_eq!(dt.round_to(&step).unwrap().to_string(), "2022-01-01T12:00:00+01:00");
+    /// ```
+    pub fn round_to(&self, step: &Duration) -> Result<Self, ParseError> {
+        let step = Self::step_microseconds(step)?;
+        let timestamp = self.timestamp_microseconds();
+        let remainder = timestamp.rem_euclid(step);
+        let rounded = if remainder * 2 >= step {
+            timestamp - remainder + step
+        } else {
+            timestamp - remainder
+        };
+        self.with_timestamp_microseconds(rounded)
+    }
+
+    /// Round the datetime down to the previous multiple of `step`, the timezone offset is unchanged.
+    ///
+    /// As with [DateTime::round_to], multiples are counted from `1970-01-01T00:00:00` in the datetime's own
+    /// timezone, datetimes which are already a multiple of `step` are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - the duration to round down to a multiple of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:07:30Z").unwrap();
+    /// let step = Duration::parse_str("PT5M").unwrap();
+    /// assert_eq!(dt.floor_to(&step).unwrap().to_string(), "2022-01-01T12:05:00Z");
+    /// ```
+    pub fn floor_to(&self, step: &Duration) -> Result<Self, ParseError> {
+        let step = Self::step_microseconds(step)?;
+        let timestamp = self.timestamp_microseconds();
+        self.with_timestamp_microseconds(timestamp - timestamp.rem_euclid(step))
+    }
+
+    /// Round the datetime up to the next multiple of `step`, the timezone offset is unchanged.
+    ///
+    /// As with [DateTime::round_to], multiples are counted from `1970-01-01T00:00:00` in the datetime's own
+    /// timezone, datetimes which are already a multiple of `step` are unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `step` - the duration to round up to a multiple of
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:07:30Z").unwrap();
+    /// let step = Duration::parse_str("PT1H").unwrap();
+    /// assert_eq!(dt.ceil_to(&step).unwrap().to_string(), "2022-01-01T13:00:00Z");
+    /// ```
+    pub fn ceil_to(&self, step: &Duration) -> Result<Self, ParseError> {
+        let step = Self::step_microseconds(step)?;
+        let timestamp = self.timestamp_microseconds();
+        match timestamp.rem_euclid(step) {
+            0 => self.with_timestamp_microseconds(timestamp),
+            remainder => self.with_timestamp_microseconds(timestamp - remainder + step),
+        }
+    }
+
+    /// Magnitude of `step` in microseconds, which must not be zero.
+    fn step_microseconds(step: &Duration) -> Result<i128, ParseError> {
+        let step = (step.day as i128 * 86_400 + step.second as i128) * 1_000_000 + step.microsecond as i128;
+        if step == 0 {
+            Err(ParseError::DurationStepZero)
+        } else {
+            Ok(step)
+        }
+    }
+
+    /// Microseconds since `1970-01-01T00:00:00`, ignoring the timezone offset.
+    fn timestamp_microseconds(&self) -> i128 {
+        self.timestamp() as i128 * 1_000_000 + self.time.microsecond as i128
+    }
+
+    /// Build a datetime from microseconds as given by [DateTime::timestamp_microseconds], with the same timezone
+    /// offset as `self`.
+    fn with_timestamp_microseconds(&self, timestamp: i128) -> Result<Self, ParseError> {
+        let second = timestamp.div_euclid(1_000_000);
+        let microsecond = timestamp.rem_euclid(1_000_000) as u32;
+        let second = match i64::try_from(second) {
+            Ok(second) => second,
+            Err(_) if second < 0 => return Err(ParseError::DateTooSmall),
+            Err(_) => return Err(ParseError::DateTooLarge),
+        };
+        let (date, time_second) = Date::from_timestamp_calc(second)?;
+        let mut time = Time::from_timestamp(time_second, microsecond)?;
+        time.tz_offset = self.time.tz_offset;
+        Ok(Self { date, time })
+    }
+
+    /// Subtract a duration from the datetime, the timezone offset is unchanged.
+    ///
+    /// See [DateTime::add_duration] for details of errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - the duration to subtract, may be negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T12:13:14Z").unwrap();
+    /// let d = Duration::parse_str("PT13H").unwrap();
+    /// assert_eq!(dt.sub_duration(&d).unwrap().to_string(), "2021-12-31T23:13:14Z");
+    /// ```
+    pub fn sub_duration(&self, duration: &Duration) -> Result<Self, ParseError> {
+        let mut negated = duration.clone();
+        negated.positive = !negated.positive;
+        self.add_duration(&negated)
+    }
+
+    /// The datetime `duration` before this one, an alias for [DateTime::sub_duration].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T00:00:00Z").unwrap();
+    /// let d = Duration::parse_str("P1D").unwrap();
+    /// assert_eq!(dt.ago(&d).unwrap().to_string(), "2021-12-31T00:00:00Z");
+    /// ```
+    #[inline]
+    pub fn ago(&self, duration: &Duration) -> Result<Self, ParseError> {
+        self.sub_duration(duration)
+    }
+
+    /// The datetime `duration` after this one, an alias for [DateTime::add_duration].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{DateTime, Duration};
+    ///
+    /// let dt = DateTime::parse_str("2022-01-01T00:00:00Z").unwrap();
+    /// let d = Duration::parse_str("P1D").unwrap();
+    /// assert_eq!(dt.hence(&d).unwrap().to_string(), "2022-01-02T00:00:00Z");
+    /// ```
+    #[inline]
+    pub fn hence(&self, duration: &Duration) -> Result<Self, ParseError> {
+        self.add_duration(duration)
+    }
+
+    /// The difference `self - other` decomposed into calendar years, months, days and time.
+    ///
+    /// Months are counted from the earlier datetime, where a day doesn't exist in the later month it's
+    /// limited to the end of the month, so `2020-01-31` to `2020-02-29` is `1` month and `2020-02-29` to
+    /// `2021-02-28` is `1` year. The remainder is given in days, hours, minutes, seconds and microseconds.
+    ///
+    /// If both datetimes have a timezone offset, `other` is first converted to the offset of `self`,
+    /// otherwise the local date and time are used, this returns an error only if that conversion is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt1 = DateTime::parse_str("2022-03-01T12:00:00").unwrap();
+    /// let dt2 = DateTime::parse_str("2020-01-31T06:30:00").unwrap();
+    /// let delta = dt1.calendar_diff(&dt2).unwrap();
+    /// assert!(delta.positive);
+    /// assert_eq!((delta.years, delta.months, delta.days), (2, 1, 1));
+    /// assert_eq!((delta.hours, delta.minutes, delta.seconds), (5, 30, 0));
+    /// ```
+    pub fn calendar_diff(&self, other: &DateTime) -> Result<CalendarDelta, ParseError> {
+        let other = match (self.time.tz_offset, other.time.tz_offset) {
+            (Some(tz_offset), Some(_)) => other.in_timezone(tz_offset)?,
+            _ => other.clone(),
+        };
+        let key = |dt: &DateTime| (dt.date.clone(), dt.time.total_seconds(), dt.time.microsecond);
+        let positive = key(self) >= key(&other);
+        let (start, end) = if positive { (&other, self) } else { (self, &other) };
+
+        // the most whole months after `start` which isn't after `end`
+        let mut months = (end.date.year as u32 * 12 + end.date.month as u32)
+            - (start.date.year as u32 * 12 + start.date.month as u32);
+        let mut month_start = add_months(&start.date, months);
+        if (month_start.clone(), start.time.total_seconds(), start.time.microsecond) > key(end) {
+            months -= 1;
+            month_start = add_months(&start.date, months);
+        }
+
+        let remainder = (end.date.timestamp() - month_start.timestamp()) * 1_000_000
+            + (end.time.total_seconds() as i64 - start.time.total_seconds() as i64) * 1_000_000
+            + (end.time.microsecond as i64 - start.time.microsecond as i64);
+        let seconds = remainder / 1_000_000;
+        Ok(CalendarDelta {
+            positive,
+            years: (months / 12) as u16,
+            months: (months % 12) as u8,
+            days: (seconds / 86_400) as u8,
+            hours: (seconds % 86_400 / 3_600) as u8,
+            minutes: (seconds % 3_600 / 60) as u8,
+            seconds: (seconds % 60) as u8,
+            microseconds: (remainder % 1_000_000) as u32,
+        })
+    }
+
     /// Unix timestamp (seconds since epoch, 1970-01-01T00:00:00) omitting timezone offset
     /// (or equivalently comparing to 1970-01-01T00:00:00 in the same timezone as self)
     ///
@@ -581,6 +1674,63 @@ impl DateTime {
         self.date.timestamp() + self.time.total_seconds() as i64
     }
 
+    /// Unix timestamp in seconds including the fraction of a second, as with [Self::timestamp] the timezone
+    /// offset is omitted.
+    ///
+    /// **Note:** this is lossy, `f64` cannot represent every microsecond across the supported range of dates,
+    /// microsecond precision is only guaranteed for timestamps within around 285 years of 1970.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_str("1970-01-02T00:00:00.5").unwrap();
+    /// assert_eq!(dt.timestamp_f64(), 86_400.5);
+    /// ```
+    pub fn timestamp_f64(&self) -> f64 {
+        self.timestamp() as f64 + self.time.microsecond as f64 / 1_000_000.0
+    }
+
+    /// Modified Julian Date, the number of days including the fraction of a day since `1858-11-17T00:00:00`,
+    /// as with [Self::timestamp] the timezone offset is omitted.
+    ///
+    /// **Note:** this is lossy, the precision of `f64` is around a microsecond for current dates, falling to
+    /// around 50 microseconds for dates near `9999-12-31`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_str("2000-01-01T12:00:00").unwrap();
+    /// assert_eq!(dt.to_mjd(), 51_544.5);
+    /// ```
+    pub fn to_mjd(&self) -> f64 {
+        // 1970-01-01 is MJD 40587, the whole days are added separately to preserve precision in the fraction
+        let days = self.date.timestamp().div_euclid(86_400) + 40_587;
+        let day_seconds = self.time.seconds_of_day_f64();
+        days as f64 + day_seconds / 86_400.0
+    }
+
+    /// Unix timestamp in milliseconds including the fraction of a millisecond, e.g. for use with
+    /// JavaScript's `Date`. As with [Self::timestamp] the timezone offset is omitted.
+    ///
+    /// **Note:** this is lossy, `f64` cannot represent every microsecond across the supported range of dates,
+    /// this should only be relied on to millisecond precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt = DateTime::parse_str("1970-01-02T00:00:00.5").unwrap();
+    /// assert_eq!(dt.timestamp_millis_f64(), 86_400_500.0);
+    /// ```
+    pub fn timestamp_millis_f64(&self) -> f64 {
+        self.timestamp() as f64 * 1_000.0 + self.time.microsecond as f64 / 1_000.0
+    }
+
     /// Unix timestamp assuming epoch is in zulu timezone (1970-01-01T00:00:00Z) and accounting for
     /// timezone offset.
     ///
@@ -607,4 +1757,64 @@ impl DateTime {
             None => self.timestamp(),
         }
     }
+
+    /// Whether two datetimes represent the same instant, unlike `==` the timezone offsets don't need to match.
+    ///
+    /// This compares [Self::timestamp_tz] and microseconds, so naïve datetimes are treated as UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::DateTime;
+    ///
+    /// let dt_uk_3pm = DateTime::parse_str("2000-01-01T15:00:00Z").unwrap();
+    /// let dt_france_4pm = DateTime::parse_str("2000-01-01T16:00:00+01:00").unwrap();
+    /// assert_ne!(dt_uk_3pm, dt_france_4pm);
+    /// assert!(dt_uk_3pm.eq_instant(&dt_france_4pm));
+    /// ```
+    pub fn eq_instant(&self, other: &Self) -> bool {
+        self.timestamp_tz() == other.timestamp_tz() && self.time.microsecond == other.time.microsecond
+    }
+}
+
+const FIXED_UTC_TEMPLATE: &[u8; 20] = b"0000-00-00T00:00:00Z";
+const ASCII_ZEROS: u64 = u64::from_le_bytes([b'0'; 8]);
+
+/// `(start, digits_mask, separators)` for each 8 byte chunk of `FIXED_UTC_TEMPLATE`, `digits_mask` has
+/// `0xff` for each digit byte, `separators` holds the expected non-digit bytes
+const FIXED_UTC_CHUNKS: [(usize, u64, u64); 3] = [fixed_utc_chunk(0), fixed_utc_chunk(8), fixed_utc_chunk(12)];
+
+const fn fixed_utc_chunk(start: usize) -> (usize, u64, u64) {
+    let mut digits_mask = [0u8; 8];
+    let mut separators = [0u8; 8];
+    let mut i = 0;
+    while i < 8 {
+        match FIXED_UTC_TEMPLATE[start + i] {
+            b'0' => digits_mask[i] = 0xff,
+            c => separators[i] = c,
+        }
+        i += 1;
+    }
+    (start, u64::from_le_bytes(digits_mask), u64::from_le_bytes(separators))
+}
+
+/// Check all 8 bytes of `chunk` are ascii digits.
+///
+/// Subtracting `b'0'` sets a byte's high bit if it's less than `b'0'`, adding `0x46` sets it if it's greater
+/// than `b'9'`, bytes `>= 0x80` set the high bit in one or other. Carries and borrows between bytes can only
+/// originate from a byte which is not a digit, so the result is zero exactly when all bytes are digits.
+#[inline(always)]
+fn all_digits(chunk: u64) -> bool {
+    let below = chunk.wrapping_sub(ASCII_ZEROS);
+    let above = chunk.wrapping_add(u64::from_le_bytes([0x46; 8]));
+    (below | above) & u64::from_le_bytes([0x80; 8]) == 0
+}
+
+/// Add whole months to a date, a day which doesn't exist in the new month is limited to the end of the month.
+fn add_months(date: &Date, months: u32) -> Date {
+    let total = date.year as u32 * 12 + (date.month as u32 - 1) + months;
+    let year = (total / 12) as u16;
+    let month = (total % 12) as u8 + 1;
+    let day = date.day.min(days_in_month(year, month).unwrap_or(31));
+    Date { year, month, day }
 }
@@ -1,8 +1,11 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::str::FromStr;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
 
-use crate::{time::TimeConfig, ParseError, TimeConfigBuilder};
+use crate::numbers::{round, trunc};
+use crate::{get_digit, time::TimeConfig, ParseError};
 
 /// A Duration
 ///
@@ -11,6 +14,8 @@ use crate::{time::TimeConfig, ParseError, TimeConfigBuilder};
 ///   see [wikipedia](https://en.wikipedia.org/wiki/ISO_8601#Durations) for more details,
 ///   `W` for weeks is also allowed before the `T` separator - **Note**: `W` is allowed combined
 ///   with other quantities which is a slight deviation from the ISO 8601 standard.
+/// * `PYYYY-MM-DDTHH:MM:SS` - ISO 8601 alternative duration format, the time part is optional and
+///   allows the same formats as [crate::Time]
 /// * `HH:MM:SS` - any of the above time formats are allowed to represent a duration
 /// * `D days, HH:MM:SS` - time prefixed by `X days`, case-insensitive,
 ///   spaces `s` and `,` are all optional
@@ -82,7 +87,7 @@ impl fmt::Display for Duration {
             if sec != 0 || self.microsecond != 0 {
                 write!(f, "{sec}")?;
                 if self.microsecond != 0 {
-                    let s = format!("{:06}", self.microsecond);
+                    let s = alloc::format!("{:06}", self.microsecond);
                     write!(f, ".{}", s.trim_end_matches('0'))?;
                 }
                 write!(f, "S")?;
@@ -167,6 +172,30 @@ macro_rules! checked {
 }
 
 impl Duration {
+    /// A duration of zero length.
+    pub const ZERO: Self = Self {
+        positive: true,
+        day: 0,
+        second: 0,
+        microsecond: 0,
+    };
+
+    /// The longest positive duration which can be represented, `P2739726Y9DT23H59M59.999999S`.
+    pub const MAX: Self = Self {
+        positive: true,
+        day: 999_999_999,
+        second: 86_399,
+        microsecond: 999_999,
+    };
+
+    /// The longest negative duration which can be represented, `-P2739726Y9DT23H59M59.999999S`.
+    pub const MIN: Self = Self {
+        positive: false,
+        day: 999_999_999,
+        second: 86_399,
+        microsecond: 999_999,
+    };
+
     /// Create a duration from raw values.
     ///
     /// # Arguments
@@ -178,8 +207,9 @@ impl Duration {
     /// `second` and `microsecond` are normalised to be in the ranges 0 to `86_400` and 0 to `999_999`
     /// respectively.
     ///
-    /// Due to the limit on days, the maximum duration which can be represented is `P2739726Y9DT86399.999999S`,
-    /// that is 1 microsecond short of 2,739,726 years and 10 days, positive or negative.
+    /// Due to the limit on days, the maximum duration which can be represented is `P2739726Y9DT23H59M59.999999S`
+    /// ([Duration::MAX] and [Duration::MIN]), that is 1 microsecond short of 2,739,726 years and 10 days,
+    /// positive or negative.
     ///
     /// # Examples
     ///
@@ -261,10 +291,11 @@ impl Duration {
     /// ```
     #[inline]
     pub fn parse_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
-        Duration::parse_bytes_with_config(bytes, &TimeConfigBuilder::new().build())
+        Duration::parse_bytes_with_duration_config(bytes, &DurationConfigBuilder::new().build())
     }
 
-    /// Same as `Duration::parse_bytes` but with a TimeConfig component.
+    /// Same as `Duration::parse_bytes` but with a TimeConfig component, duration specific options are
+    /// disabled, use [Duration::parse_bytes_with_duration_config] to set them.
     ///
     /// # Arguments
     ///
@@ -290,18 +321,60 @@ impl Duration {
     /// ```
     #[inline]
     pub fn parse_bytes_with_config(bytes: &[u8], config: &TimeConfig) -> Result<Self, ParseError> {
-        let (positive, bytes) = match bytes {
+        let config = DurationConfigBuilder::new().time_config(config.clone()).build();
+        Duration::parse_bytes_with_duration_config(bytes, &config)
+    }
+
+    /// Same as `Duration::parse_bytes` but with a `DurationConfig`.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The bytes to parse
+    /// * `config` - The `DurationConfig` to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{Duration, DurationConfigBuilder};
+    ///
+    /// let config = DurationConfigBuilder::new().allow_dotnet_timespan(true).build();
+    /// let d = Duration::parse_bytes_with_duration_config(b"1.12:30:00", &config).unwrap();
+    /// assert_eq!(d.to_string(), "P1DT12H30M");
+    /// ```
+    pub fn parse_bytes_with_duration_config(bytes: &[u8], config: &DurationConfig) -> Result<Self, ParseError> {
+        let time_config = &config.time_config;
+        time_config.check_input_len(bytes)?;
+        let (mut positive, bytes) = match time_config.trim(bytes) {
             [b'-', bytes @ ..] => (false, bytes),
             [b'+', bytes @ ..] | bytes => (true, bytes),
         };
         let mut d = match bytes {
             [] => return Err(ParseError::TooShort),
-            [b'P', iso_duration @ ..] => Self::parse_iso_duration(iso_duration)?,
+            b"0" | b"P0" if config.allow_zero_duration_shorthand => Self::ZERO,
+            [p, iso_duration @ ..] if *p == b'P' || (*p == b'p' && config.case_insensitive_designators) => {
+                if matches!(
+                    iso_duration,
+                    [b'0'..=b'9', b'0'..=b'9', b'0'..=b'9', b'0'..=b'9', b'-', ..]
+                ) {
+                    Self::parse_iso_alternative_duration(iso_duration, time_config)?
+                } else {
+                    let d = Self::parse_iso_duration(iso_duration, config)?;
+                    // signed components summed to a negative duration, e.g. `PT-1H30M`
+                    if !d.positive {
+                        positive = !positive;
+                    }
+                    d
+                }
+            }
+            bytes if config.allow_dotnet_timespan && Self::is_dotnet_timespan_format(bytes) => {
+                Self::parse_dotnet_timespan(bytes, time_config)?
+            }
+            bytes if crate::is_relative_keyword(bytes) => return Err(ParseError::RelativeNotSupported),
             bytes => {
                 if Self::is_duration_date_format(bytes) || bytes.len() < 5 {
-                    Self::parse_days_time(bytes, config)?
+                    Self::parse_days_time(bytes, time_config)?
                 } else {
-                    Self::parse_time(bytes, config)?
+                    Self::parse_time(bytes, time_config)?
                 }
             }
         };
@@ -311,6 +384,65 @@ impl Duration {
         Ok(d)
     }
 
+    /// Description of the ISO 8601 format produced by `to_string()`, see [crate::Date::format_hint].
+    ///
+    /// Years are always 365 days and months aren't used, a zero duration is `PT0S`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// assert_eq!(Duration::format_hint(), "[-]P[nY][nD][T[nH][nM][n[.f]S]]");
+    /// ```
+    pub const fn format_hint() -> &'static str {
+        "[-]P[nY][nD][T[nH][nM][n[.f]S]]"
+    }
+
+    /// Compact representation of the duration with space separated units, e.g. `1y 63d 4h 5m 6s`, using the
+    /// same years, days, hours, minutes and seconds as the ISO 8601 `Display`, zero units are omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::parse_str("P1Y2M3DT4H5M6.5S").unwrap();
+    /// assert_eq!(d.to_string(), "P1Y63DT4H5M6.5S");
+    /// assert_eq!(d.to_compact_string(), "1y 63d 4h 5m 6.5s");
+    /// assert_eq!(Duration::ZERO.to_compact_string(), "0s");
+    /// ```
+    pub fn to_compact_string(&self) -> String {
+        let mut units: Vec<String> = Vec::new();
+        let year = self.day / 365;
+        if year != 0 {
+            units.push(alloc::format!("{year}y"));
+        }
+        let day = self.day % 365;
+        if day != 0 {
+            units.push(alloc::format!("{day}d"));
+        }
+        let (hour, minute, sec) = self.to_hms();
+        if hour != 0 {
+            units.push(alloc::format!("{hour}h"));
+        }
+        if minute != 0 {
+            units.push(alloc::format!("{minute}m"));
+        }
+        if self.microsecond != 0 {
+            let s = alloc::format!("{:06}", self.microsecond);
+            units.push(alloc::format!("{sec}.{}s", s.trim_end_matches('0')));
+        } else if sec != 0 || units.is_empty() {
+            units.push(alloc::format!("{sec}s"));
+        }
+        let compact = units.join(" ");
+        if self.positive {
+            compact
+        } else {
+            alloc::format!("-{compact}")
+        }
+    }
+
     /// Total number of seconds in the duration (days + seconds) with sign based on `self.positive`
     #[inline]
     pub fn signed_total_seconds(&self) -> i64 {
@@ -318,6 +450,27 @@ impl Duration {
         sign * (self.day as i64 * 86400 + self.second as i64)
     }
 
+    /// Same as [Duration::signed_total_seconds] but with overflow checked arithmetic.
+    ///
+    /// This never returns `None`, even with `day` and `second` at `u32::MAX`, the total is around `3.7e14`
+    /// which is well within the range of `i64`, it's provided for composing with other checked arithmetic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// assert_eq!(Duration::MAX.checked_signed_total_seconds(), Some(86_399_999_999_999));
+    /// ```
+    #[inline]
+    pub fn checked_signed_total_seconds(&self) -> Option<i64> {
+        let sign = if self.positive { 1 } else { -1 };
+        (self.day as i64)
+            .checked_mul(86400)?
+            .checked_add(self.second as i64)?
+            .checked_mul(sign)
+    }
+
     /// Microseconds in the duration with sign based on `self.positive`
     #[inline]
     pub fn signed_microseconds(&self) -> i32 {
@@ -325,6 +478,227 @@ impl Duration {
         sign * self.microsecond as i32
     }
 
+    /// Create a duration from a number of milliseconds, negative values give a negative duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::from_millis(-90_061_001).unwrap();
+    /// assert_eq!(d.to_string(), "-P1DT1H1M1.001S");
+    /// ```
+    pub fn from_millis(millis: i64) -> Result<Self, ParseError> {
+        let abs_millis = millis.unsigned_abs();
+        let day = u32::try_from(abs_millis / 86_400_000).map_err(|_| ParseError::DurationDaysTooLarge)?;
+        let remaining_millis = (abs_millis % 86_400_000) as u32;
+        Self::new(
+            millis >= 0,
+            day,
+            remaining_millis / 1_000,
+            (remaining_millis % 1_000) * 1_000,
+        )
+    }
+
+    /// Total number of milliseconds in the duration with sign based on `self.positive`, microseconds are
+    /// truncated towards zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::parse_str("-PT1.0015S").unwrap();
+    /// assert_eq!(d.as_millis(), -1_001);
+    /// ```
+    #[inline]
+    pub fn as_millis(&self) -> i64 {
+        self.signed_total_seconds() * 1_000 + self.signed_microseconds() as i64 / 1_000
+    }
+
+    /// Create a duration from a number of seconds, negative values give a negative duration.
+    ///
+    /// Seconds are rounded to the nearest microsecond, however since `f64` has 53 bits of precision,
+    /// microseconds are only exact for durations up to around 285 years, beyond that precision is lost.
+    ///
+    /// Returns `Err(ParseError::DurationDaysTooLarge)` if the duration is too large to represent,
+    /// or `Err(ParseError::DurationInvalidNumber)` if `seconds` is not a number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::from_seconds_f64(-90_061.5).unwrap();
+    /// assert_eq!(d.to_string(), "-P1DT1H1M1.5S");
+    /// ```
+    pub fn from_seconds_f64(seconds: f64) -> Result<Self, ParseError> {
+        if seconds.is_nan() {
+            return Err(ParseError::DurationInvalidNumber);
+        }
+        let abs_seconds = seconds.abs();
+        // 1 microsecond more than `Duration::MAX`
+        if abs_seconds >= 86_400_000_000_000f64 {
+            return Err(ParseError::DurationDaysTooLarge);
+        }
+        let whole_seconds = trunc(abs_seconds);
+        let microsecond = round((abs_seconds - whole_seconds) * 1_000_000f64) as u32;
+        let whole_seconds = whole_seconds as u64;
+        let mut d = Self::new(
+            seconds >= 0f64,
+            (whole_seconds / 86_400) as u32,
+            (whole_seconds % 86_400) as u32,
+            microsecond,
+        )?;
+        if d.day == 0 && d.second == 0 && d.microsecond == 0 {
+            d.positive = true;
+        }
+        Ok(d)
+    }
+
+    /// Total number of seconds in the duration with sign based on `self.positive`, including microseconds
+    /// as a fraction of a second.
+    ///
+    /// As with [Duration::from_seconds_f64], microsecond precision is lost for durations longer than
+    /// around 285 years.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::parse_str("-PT1M1.5S").unwrap();
+    /// assert_eq!(d.as_seconds_f64(), -61.5);
+    /// ```
+    #[inline]
+    pub fn as_seconds_f64(&self) -> f64 {
+        self.signed_total_seconds() as f64 + self.signed_microseconds() as f64 / 1_000_000f64
+    }
+
+    /// Break the duration into `(positive, days, hours, minutes, seconds, microseconds)`.
+    ///
+    /// Hours, minutes and seconds are derived from `second`, the other fields are returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::parse_str("-P1DT4H5M6.5S").unwrap();
+    /// assert_eq!(d.as_dhms_micros(), (false, 1, 4, 5, 6, 500_000));
+    /// ```
+    pub fn as_dhms_micros(&self) -> (bool, u32, u32, u32, u32, u32) {
+        let (hour, minute, second) = self.to_hms();
+        (self.positive, self.day, hour, minute, second, self.microsecond)
+    }
+
+    /// Compare two durations by their magnitude, ignoring `positive`.
+    ///
+    /// Unlike the `PartialOrd` implementation, this doesn't consider the sign of durations,
+    /// so `-P2D` is greater than `+P1D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::cmp::Ordering;
+    /// use speedate::Duration;
+    ///
+    /// let d1 = Duration::parse_str("-P2D").unwrap();
+    /// let d2 = Duration::parse_str("+P1D").unwrap();
+    /// assert!(d1 < d2);
+    /// assert_eq!(d1.cmp_magnitude(&d2), Ordering::Greater);
+    /// ```
+    pub fn cmp_magnitude(&self, other: &Self) -> Ordering {
+        let self_t = (self.day, self.second, self.microsecond);
+        let other_t = (other.day, other.second, other.microsecond);
+        self_t.cmp(&other_t)
+    }
+
+    /// Restrict the duration to between `min` and `max` inclusive using the sign-aware ordering of
+    /// [Duration::partial_cmp].
+    ///
+    /// # Panics
+    ///
+    /// If `min` is greater than `max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let min = Duration::parse_str("-PT1H").unwrap();
+    /// let max = Duration::parse_str("PT1H").unwrap();
+    /// let d = Duration::parse_str("PT2H").unwrap();
+    /// assert_eq!(d.clamp(&min, &max).to_string(), "PT1H");
+    /// let d = Duration::parse_str("-PT30M").unwrap();
+    /// assert_eq!(d.clamp(&min, &max).to_string(), "-PT30M");
+    /// ```
+    pub fn clamp(&self, min: &Self, max: &Self) -> Self {
+        assert!(min <= max, "min must be less than or equal to max");
+        if self < min {
+            min.clone()
+        } else if self > max {
+            max.clone()
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Add two durations, clamping the result to [Duration::MIN] and [Duration::MAX] rather than returning
+    /// an error when it's too large to represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::parse_str("P1DT12H").unwrap();
+    /// assert_eq!(d.saturating_add(&Duration::parse_str("-PT13H").unwrap()).to_string(), "PT23H");
+    /// assert_eq!(Duration::MAX.saturating_add(&d), Duration::MAX);
+    /// ```
+    pub fn saturating_add(&self, other: &Self) -> Self {
+        Self::from_total_microseconds_saturating(self.signed_total_microseconds() + other.signed_total_microseconds())
+    }
+
+    /// Subtract `other` from the duration, clamping the result to [Duration::MIN] and [Duration::MAX] rather
+    /// than returning an error when it's too large to represent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Duration;
+    ///
+    /// let d = Duration::parse_str("PT1H").unwrap();
+    /// assert_eq!(d.saturating_sub(&Duration::parse_str("PT2H").unwrap()).to_string(), "-PT1H");
+    /// assert_eq!(Duration::MIN.saturating_sub(&d), Duration::MIN);
+    /// ```
+    pub fn saturating_sub(&self, other: &Self) -> Self {
+        Self::from_total_microseconds_saturating(self.signed_total_microseconds() - other.signed_total_microseconds())
+    }
+
+    /// Total number of microseconds with sign based on `self.positive`, this can exceed the range of `i64`.
+    fn signed_total_microseconds(&self) -> i128 {
+        let total =
+            (i128::from(self.day) * 86_400 + i128::from(self.second)) * 1_000_000 + i128::from(self.microsecond);
+        if self.positive {
+            total
+        } else {
+            -total
+        }
+    }
+
+    /// Create a duration from a signed number of microseconds, clamped to [Duration::MIN] and [Duration::MAX].
+    fn from_total_microseconds_saturating(total: i128) -> Self {
+        let max = Self::MAX.signed_total_microseconds();
+        let abs_total = total.clamp(-max, max).unsigned_abs();
+        Self {
+            positive: total >= 0,
+            day: (abs_total / 86_400_000_000) as u32,
+            second: (abs_total / 1_000_000 % 86_400) as u32,
+            microsecond: (abs_total % 1_000_000) as u32,
+        }
+    }
+
     fn normalize(&mut self) -> Result<(), ParseError> {
         if self.microsecond >= 1_000_000 {
             self.second = self
@@ -348,77 +722,166 @@ impl Duration {
     }
 
     /// Parse ISO duration (excluding the 'P' prefix)
-    fn parse_iso_duration(bytes: &[u8]) -> Result<Self, ParseError> {
+    ///
+    /// `positive` is only false if `allow_signed_components` is set and the components sum to a
+    /// negative duration.
+    fn parse_iso_duration(bytes: &[u8], config: &DurationConfig) -> Result<Self, ParseError> {
         let mut got_t = false;
         let mut last_had_fraction = false;
-        let mut position: usize = 0;
-        let mut day: u32 = 0;
-        let mut second: u32 = 0;
-        let mut microsecond: u32 = 0;
-        loop {
-            match bytes.get(position).copied() {
-                Some(b'T') => {
+        // (day, second, microsecond) totals of positive and negative components
+        let mut totals = [(0_u32, 0_u32, 0_u32); 2];
+        // the remaining bytes are consumed from the front, so each byte is only read once
+        let mut rest = bytes;
+        while let [c, tail @ ..] = rest {
+            match *c {
+                b'T' | b't' if *c == b'T' || config.case_insensitive_designators => {
                     if got_t {
                         return Err(ParseError::DurationTRepeated);
                     }
                     got_t = true;
+                    rest = tail;
                 }
-                Some(c) => {
-                    let (value, op_fraction, offset) = Self::parse_number_frac(&bytes[position..], c)?;
+                mut c => {
+                    let negative = c == b'-' && config.allow_signed_components;
+                    if negative {
+                        rest = tail;
+                        c = rest.first().copied().ok_or(ParseError::DurationInvalidNumber)?;
+                    }
+                    let (day, second, microsecond) = &mut totals[usize::from(negative)];
+                    let (value, op_fraction, offset) = Self::parse_number_frac(rest, c)?;
                     if last_had_fraction {
                         return Err(ParseError::DurationInvalidFraction);
                     }
                     if op_fraction.is_some() {
                         last_had_fraction = true;
                     }
-                    position += offset;
+                    let unit = match &rest[offset..] {
+                        [unit, tail @ ..] => {
+                            rest = tail;
+                            match config.case_insensitive_designators {
+                                true => Some(unit.to_ascii_uppercase()),
+                                false => Some(*unit),
+                            }
+                        }
+                        [] => None,
+                    };
                     if got_t {
-                        let mult: u32 = match bytes.get(position).copied() {
+                        let mult: u32 = match unit {
                             Some(b'H') => 3600,
                             Some(b'M') => 60,
                             Some(b'S') => 1,
+                            Some(b'W') => return Err(ParseError::DurationWeekAfterT),
                             _ => return Err(ParseError::DurationInvalidTimeUnit),
                         };
-                        second = checked!(second + checked!(mult * value));
+                        *second = checked!(second + checked!(mult * value));
                         if let Some(fraction) = op_fraction {
                             let extra_seconds = fraction * mult as f64;
-                            let extra_full_seconds = extra_seconds.trunc();
-                            second = checked!(second + extra_full_seconds as u32);
-                            let micro_extra = ((extra_seconds - extra_full_seconds) * 1_000_000.0).round() as u32;
-                            microsecond = checked!(microsecond + micro_extra);
+                            let extra_full_seconds = trunc(extra_seconds);
+                            *second = checked!(second + extra_full_seconds as u32);
+                            let micro_extra = round((extra_seconds - extra_full_seconds) * 1_000_000.0) as u32;
+                            *microsecond = checked!(microsecond + micro_extra);
                         }
                     } else {
-                        let mult: u32 = match bytes.get(position).copied() {
+                        let mult: u32 = match unit {
                             Some(b'Y') => 365,
                             Some(b'M') => 30,
                             Some(b'W') => 7,
                             Some(b'D') => 1,
+                            // `M` before `T` is months, so only hours and seconds are unambiguous
+                            Some(b'H' | b'S') => return Err(ParseError::DurationTimeUnitBeforeT),
                             _ => return Err(ParseError::DurationInvalidDateUnit),
                         };
-                        day = checked!(day + checked!(value * mult));
+                        *day = checked!(day + checked!(value * mult));
                         if let Some(fraction) = op_fraction {
                             let extra_days = fraction * mult as f64;
-                            let extra_full_days = extra_days.trunc();
-                            day = checked!(day + extra_full_days as u32);
+                            let extra_full_days = trunc(extra_days);
+                            *day = checked!(day + extra_full_days as u32);
                             let extra_seconds = (extra_days - extra_full_days) * 86_400.0;
-                            let extra_full_seconds = extra_seconds.trunc();
-                            second = checked!(second + extra_full_seconds as u32);
-                            microsecond += ((extra_seconds - extra_full_seconds) * 1_000_000.0).round() as u32;
+                            let extra_full_seconds = trunc(extra_seconds);
+                            *second = checked!(second + extra_full_seconds as u32);
+                            let micro_extra = round((extra_seconds - extra_full_seconds) * 1_000_000.0) as u32;
+                            *microsecond = checked!(microsecond + micro_extra);
                         }
                     }
                 }
-                None => break,
             }
-            position += 1;
         }
         // require at least one field
-        if position < 2 {
+        if bytes.len() < 2 {
             return Err(ParseError::TooShort);
         }
 
+        let [(day, second, microsecond), negative_total] = totals;
+        if negative_total == (0, 0, 0) {
+            return Ok(Self {
+                positive: true,
+                day,
+                second,
+                microsecond,
+            });
+        }
+        let total_microseconds = |(day, second, microsecond): (u32, u32, u32)| {
+            (i128::from(day) * 86_400 + i128::from(second)) * 1_000_000 + i128::from(microsecond)
+        };
+        let diff = total_microseconds(totals[0]) - total_microseconds(negative_total);
+        let abs_diff = diff.unsigned_abs();
+        Ok(Self {
+            positive: diff >= 0,
+            day: u32::try_from(abs_diff / 86_400_000_000).map_err(|_| ParseError::DurationValueTooLarge)?,
+            second: (abs_diff / 1_000_000 % 86_400) as u32,
+            microsecond: (abs_diff % 1_000_000) as u32,
+        })
+    }
+
+    /// Parse ISO duration in the alternative format `YYYY-MM-DDThh:mm:ss` (excluding the 'P' prefix)
+    fn parse_iso_alternative_duration(bytes: &[u8], config: &TimeConfig) -> Result<Self, ParseError> {
+        if bytes.len() < 10 {
+            return Err(ParseError::TooShort);
+        }
+        let y1 = get_digit!(bytes, 0, InvalidCharYear) as u32;
+        let y2 = get_digit!(bytes, 1, InvalidCharYear) as u32;
+        let y3 = get_digit!(bytes, 2, InvalidCharYear) as u32;
+        let y4 = get_digit!(bytes, 3, InvalidCharYear) as u32;
+        let year = y1 * 1000 + y2 * 100 + y3 * 10 + y4;
+
+        if bytes.get(7).copied() != Some(b'-') {
+            return Err(ParseError::InvalidCharDateSep);
+        }
+        let m1 = get_digit!(bytes, 5, InvalidCharMonth) as u32;
+        let m2 = get_digit!(bytes, 6, InvalidCharMonth) as u32;
+        let month = m1 * 10 + m2;
+
+        let d1 = get_digit!(bytes, 8, InvalidCharDay) as u32;
+        let d2 = get_digit!(bytes, 9, InvalidCharDay) as u32;
+        let day = d1 * 10 + d2;
+
+        // ISO 8601 requires values in the alternative format not to exceed their "carry-over points"
+        if month > 12 {
+            return Err(ParseError::OutOfRangeMonth);
+        }
+        if day > 30 {
+            return Err(ParseError::OutOfRangeDay);
+        }
+
+        let (second, microsecond) = match bytes.get(10).copied() {
+            None => (0, 0),
+            Some(b'T') => {
+                let t = crate::time::PureTime::parse(bytes, 11, config)?;
+                if bytes.len() > t.position {
+                    return Err(ParseError::ExtraCharacters);
+                }
+                if t.end_of_day {
+                    (86_400, 0)
+                } else {
+                    (t.total_seconds(), t.microsecond)
+                }
+            }
+            Some(_) => return Err(ParseError::ExtraCharacters),
+        };
+
         Ok(Self {
             positive: false, // is set above
-            day,
+            day: year * 365 + month * 30 + day,
             second,
             microsecond,
         })
@@ -428,6 +891,38 @@ impl Duration {
         bytes.iter().any(|&byte| byte == b'd' || byte == b'D')
     }
 
+    /// `true` if the days are separated from the time by `.`, e.g. `1.12:30:00`
+    fn is_dotnet_timespan_format(bytes: &[u8]) -> bool {
+        match bytes.iter().position(|&byte| byte == b'.' || byte == b':') {
+            Some(position) => bytes[position] == b'.' && bytes[position + 1..].contains(&b':'),
+            None => false,
+        }
+    }
+
+    fn parse_dotnet_timespan(bytes: &[u8], config: &TimeConfig) -> Result<Self, ParseError> {
+        let (day, position) = match bytes.first().copied() {
+            Some(c) => Self::parse_number(bytes, c),
+            _ => Err(ParseError::TooShort),
+        }?;
+
+        let Some([b'.', remaining @ ..]) = bytes.get(position..) else {
+            return Err(ParseError::DurationInvalidDays);
+        };
+
+        let t = Self::parse_time(remaining, config)?;
+        if t.day > 0 {
+            // 1.24:00:00 is not allowed
+            return Err(ParseError::DurationHourValueTooLarge);
+        }
+
+        Ok(Self {
+            positive: false, // is set above
+            day,
+            second: t.second,
+            microsecond: t.microsecond,
+        })
+    }
+
     fn parse_days_time(bytes: &[u8], config: &TimeConfig) -> Result<Self, ParseError> {
         let (day, position) = match bytes.first().copied() {
             Some(c) => Self::parse_number(bytes, c),
@@ -530,9 +1025,15 @@ impl Duration {
         }
 
         let mut new_bytes = *b"00:00:00.000000";
+        let mut round_up = false;
         if 3 + remaining.len() > new_bytes.len() {
             match config.microseconds_precision_overflow_behavior {
                 crate::MicrosecondsPrecisionOverflowBehavior::Truncate => remaining = &remaining[..new_bytes.len() - 3],
+                crate::MicrosecondsPrecisionOverflowBehavior::Round => {
+                    // the 7th digit of the fraction decides whether the microseconds are rounded up
+                    round_up = matches!(remaining[new_bytes.len() - 3], b'5'..=b'9');
+                    remaining = &remaining[..new_bytes.len() - 3];
+                }
                 crate::MicrosecondsPrecisionOverflowBehavior::Error => return Err(ParseError::SecondFractionTooLong),
             }
         }
@@ -551,7 +1052,8 @@ impl Duration {
             positive: false, // is set above
             day,
             second: t.total_seconds() + (hour as u32) * 3_600,
-            microsecond: t.microsecond,
+            // carried into the seconds by `normalize`
+            microsecond: t.microsecond + round_up as u32,
         })
     }
 
@@ -595,3 +1097,110 @@ impl Duration {
         }
     }
 }
+
+/// The lesser of two durations using the sign-aware ordering of [Duration::partial_cmp], `a` if they're equal.
+///
+/// # Examples
+///
+/// ```
+/// use speedate::{duration_min, Duration};
+///
+/// let d1 = Duration::parse_str("-P2D").unwrap();
+/// let d2 = Duration::parse_str("P1D").unwrap();
+/// assert_eq!(duration_min(&d1, &d2), d1);
+/// ```
+pub fn duration_min(a: &Duration, b: &Duration) -> Duration {
+    if b < a {
+        b.clone()
+    } else {
+        a.clone()
+    }
+}
+
+/// The greater of two durations using the sign-aware ordering of [Duration::partial_cmp], `a` if they're
+/// equal.
+///
+/// # Examples
+///
+/// ```
+/// use speedate::{duration_max, Duration};
+///
+/// let d1 = Duration::parse_str("-P2D").unwrap();
+/// let d2 = Duration::parse_str("P1D").unwrap();
+/// assert_eq!(duration_max(&d1, &d2), d2);
+/// ```
+pub fn duration_max(a: &Duration, b: &Duration) -> Duration {
+    if b > a {
+        b.clone()
+    } else {
+        a.clone()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DurationConfig {
+    /// Config used when parsing the time in durations such as `12:30:00` or `P0000-00-01T12:30:00`, its
+    /// `trim_whitespace`, `skip_bom` and `max_input_len` also apply to the whole input.
+    pub time_config: TimeConfig,
+    /// Accept .NET and Go style `TimeSpan` durations with days separated from the time by `.`,
+    /// e.g. `1.12:30:00` is parsed as one day, 12 hours and 30 minutes.
+    pub allow_dotnet_timespan: bool,
+    /// Accept a `-` sign on individual components of ISO 8601 durations, the components are summed with their
+    /// signs, e.g. `PT-1H30M` is parsed as `-PT30M`.
+    pub allow_signed_components: bool,
+    /// Accept a bare `0` or `P0` as a zero duration, equivalent to `PT0S` and `P0D`.
+    pub allow_zero_duration_shorthand: bool,
+    /// Accept lowercase designators in ISO 8601 durations, e.g. `p1y2m3dt4h5m6s`, `m` is months before `t`
+    /// and minutes after it, as with `M`.
+    pub case_insensitive_designators: bool,
+}
+
+impl DurationConfig {
+    pub fn builder() -> DurationConfigBuilder {
+        DurationConfigBuilder::new()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DurationConfigBuilder {
+    time_config: Option<TimeConfig>,
+    allow_dotnet_timespan: bool,
+    allow_signed_components: bool,
+    allow_zero_duration_shorthand: bool,
+    case_insensitive_designators: bool,
+}
+
+impl DurationConfigBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    pub fn time_config(mut self, time_config: TimeConfig) -> Self {
+        self.time_config = Some(time_config);
+        self
+    }
+    pub fn allow_dotnet_timespan(mut self, allow_dotnet_timespan: bool) -> Self {
+        self.allow_dotnet_timespan = allow_dotnet_timespan;
+        self
+    }
+    pub fn allow_signed_components(mut self, allow_signed_components: bool) -> Self {
+        self.allow_signed_components = allow_signed_components;
+        self
+    }
+    pub fn allow_zero_duration_shorthand(mut self, allow_zero_duration_shorthand: bool) -> Self {
+        self.allow_zero_duration_shorthand = allow_zero_duration_shorthand;
+        self
+    }
+    pub fn case_insensitive_designators(mut self, case_insensitive_designators: bool) -> Self {
+        self.case_insensitive_designators = case_insensitive_designators;
+        self
+    }
+    pub fn build(self) -> DurationConfig {
+        DurationConfig {
+            time_config: self.time_config.unwrap_or_default(),
+            allow_dotnet_timespan: self.allow_dotnet_timespan,
+            allow_signed_components: self.allow_signed_components,
+            allow_zero_duration_shorthand: self.allow_zero_duration_shorthand,
+            case_insensitive_designators: self.case_insensitive_designators,
+        }
+    }
+}
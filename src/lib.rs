@@ -1,4 +1,6 @@
 #![doc = include_str ! ("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+extern crate alloc;
 extern crate core;
 extern crate strum;
 
@@ -8,16 +10,21 @@ mod date;
 mod datetime;
 mod duration;
 mod numbers;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod time;
 
-pub use date::Date;
-pub use datetime::DateTime;
-pub use duration::Duration;
-pub use time::{MicrosecondsPrecisionOverflowBehavior, Time, TimeConfig, TimeConfigBuilder};
+pub use date::{Date, DateConfig, DateConfigBuilder, DateFieldOrder, WeekStart};
+pub use datetime::{CalendarDelta, DateTime, ParseErrorWithInput, TzSource, ZonedDateTime};
+pub use duration::{duration_max, duration_min, Duration, DurationConfig, DurationConfigBuilder};
+pub use time::{
+    FractionSep, MicrosecondsPrecisionOverflowBehavior, RoundingMode, Time, TimeConfig, TimeConfigBuilder,
+    TimestampUnit,
+};
 
 pub use numbers::{float_parse_bytes, float_parse_str, int_parse_bytes, int_parse_str, IntFloat};
 
-/// Parsing datetime, date, time & duration values
+// Parsing datetime, date, time & duration values
 
 // get a character from the bytes as as a decimal
 macro_rules! get_digit {
@@ -142,8 +149,142 @@ pub enum ParseError {
     DateTooSmall,
     /// dates after 9999 are not supported as unix timestamps
     DateTooLarge,
-    /// numeric times may not exceed 86,399 seconds
+    /// numeric times may not exceed 86,399 seconds, nor may rounding carry a time past `23:59:59`
     TimeTooLarge,
+    /// invalid month name, expected an English month name such as `Jan` or `January`
+    InvalidMonthName,
+    /// time fields must be two digits, e.g. `02:03:04` rather than `2:3:4`
+    TimeFieldNotPadded,
+    /// unknown timezone name, expected `UTC`, `UT` or `GMT`
+    UnknownTzName,
+    /// relative values such as `now`, `today`, `yesterday` and `tomorrow` are not supported
+    RelativeNotSupported,
+    /// zone name missing or invalid, expected a name in brackets such as `[America/New_York]`
+    InvalidZoneName,
+    /// the year `0000` is not allowed
+    YearZero,
+    /// timestamp could be in seconds or milliseconds, see `TimestampUnit::InferStrict`
+    AmbiguousTimestamp,
+    /// invalid timezone second
+    InvalidCharTzSecond,
+    /// timezone second value is outside expected range of 0-59
+    OutOfRangeTzSecond,
+    /// timezone offsets with seconds are not allowed
+    TzOffsetSecondsNotAllowed,
+    /// more than one timezone was given, e.g. an offset followed by `Z`
+    DuplicateTimezone,
+    /// the step duration must not be zero
+    DurationStepZero,
+    /// unexpected whitespace in time
+    UnexpectedWhitespace,
+    /// hours or seconds in duration before the `T` designator, e.g. `P1H` rather than `PT1H`
+    DurationTimeUnitBeforeT,
+    /// weeks in duration after the `T` designator, e.g. `PT1W` rather than `P1W`
+    DurationWeekAfterT,
+    /// `Z` is a timezone and can't be used as the separator between the date and time, expected `T`
+    MisplacedTimezone,
+    /// input is longer than the configured maximum length
+    InputTooLong,
+    /// error reading the input from a reader
+    ReadError,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Generate `ParseError::code` and `ParseError::from_code` from a single list so they can't get out of sync
+macro_rules! parse_error_codes {
+    ($($variant:ident = $code:literal,)*) => {
+        impl ParseError {
+            /// A stable numeric code for the error, e.g. for use across FFI boundaries or in logs.
+            ///
+            /// Codes are assigned explicitly rather than using the enum discriminant, so they don't depend
+            /// on the order of variants, and once assigned a code is never changed or reused.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use speedate::ParseError;
+            ///
+            /// assert_eq!(ParseError::TooShort.code(), 1);
+            /// assert_eq!(ParseError::from_code(1), Some(ParseError::TooShort));
+            /// ```
+            pub fn code(&self) -> u32 {
+                match self {
+                    $(Self::$variant => $code,)*
+                }
+            }
+
+            /// The error for a code returned by [ParseError::code], `None` if the code is unknown.
+            pub fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    $($code => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+parse_error_codes! {
+    TooShort = 1,
+    InputTooLong = 2,
+    ExtraCharacters = 3,
+    InvalidCharDateTimeSep = 4,
+    MisplacedTimezone = 5,
+    InvalidCharDateSep = 6,
+    DateNotExact = 7,
+    InvalidCharYear = 8,
+    InvalidCharMonth = 9,
+    InvalidCharDay = 10,
+    InvalidCharTimeSep = 11,
+    InvalidCharHour = 12,
+    InvalidCharMinute = 13,
+    InvalidCharSecond = 14,
+    InvalidCharSecondFraction = 15,
+    InvalidCharTzSign = 16,
+    InvalidCharTzHour = 17,
+    InvalidCharTzMinute = 18,
+    OutOfRangeTzMinute = 19,
+    InvalidCharTzSecond = 20,
+    OutOfRangeTzSecond = 21,
+    TzOffsetSecondsNotAllowed = 22,
+    DuplicateTimezone = 23,
+    UnexpectedWhitespace = 24,
+    OutOfRangeTz = 25,
+    TzRequired = 26,
+    SystemTimeError = 27,
+    ReadError = 28,
+    OutOfRangeMonth = 29,
+    OutOfRangeDay = 30,
+    OutOfRangeHour = 31,
+    OutOfRangeMinute = 32,
+    OutOfRangeSecond = 33,
+    SecondFractionTooLong = 34,
+    SecondFractionMissing = 35,
+    MillisecondFractionTooLong = 36,
+    DurationInvalidNumber = 37,
+    DurationTRepeated = 38,
+    DurationInvalidFraction = 39,
+    DurationInvalidTimeUnit = 40,
+    DurationInvalidDateUnit = 41,
+    DurationTimeUnitBeforeT = 42,
+    DurationWeekAfterT = 43,
+    DurationInvalidDays = 44,
+    DurationValueTooLarge = 45,
+    DurationHourValueTooLarge = 46,
+    DurationDaysTooLarge = 47,
+    DateTooSmall = 48,
+    DateTooLarge = 49,
+    TimeTooLarge = 50,
+    DurationStepZero = 51,
+    InvalidMonthName = 52,
+    TimeFieldNotPadded = 53,
+    UnknownTzName = 54,
+    RelativeNotSupported = 55,
+    InvalidZoneName = 56,
+    YearZero = 57,
+    AmbiguousTimestamp = 58,
 }
 
 #[derive(Debug, Display, EnumMessage, PartialEq, Eq, Clone)]
@@ -151,6 +292,37 @@ pub enum ParseError {
 pub enum ConfigError {
     // SecondsPrecisionOverflowBehavior string representation, must be one of "error" or "truncate"
     UnknownMicrosecondsPrecisionOverflowBehaviorString,
+    // RoundingMode string representation, must be one of "round", "truncate" or "ceil"
+    UnknownRoundingModeString,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConfigError {}
+
+/// Used internally to read the input of `parse_reader` methods, reading stops at the end of the input or after
+/// `max_len + 1` bytes, in which case the input is too long and [ParseError::InputTooLong] is returned.
+#[cfg(feature = "std")]
+fn read_bounded<R: std::io::Read>(reader: &mut R, max_len: usize) -> Result<alloc::vec::Vec<u8>, ParseError> {
+    use std::io::Read;
+
+    let mut buf = alloc::vec::Vec::with_capacity(max_len + 1);
+    reader
+        .take(max_len as u64 + 1)
+        .read_to_end(&mut buf)
+        .map_err(|_| ParseError::ReadError)?;
+    if buf.len() > max_len {
+        Err(ParseError::InputTooLong)
+    } else {
+        Ok(buf)
+    }
+}
+
+/// Used internally to give [ParseError::RelativeNotSupported] for relative keywords like `now` rather than a
+/// less helpful error.
+fn is_relative_keyword(bytes: &[u8]) -> bool {
+    ["now", "today", "yesterday", "tomorrow"]
+        .iter()
+        .any(|keyword| bytes.eq_ignore_ascii_case(keyword.as_bytes()))
 }
 
 /// Used internally to write numbers to a buffer for `Display` of speedate types
@@ -125,3 +125,69 @@ pub(crate) fn decimal_digits(bytes: &[u8]) -> usize {
         Some(fraction) => fraction.len(),
     }
 }
+
+// `f64::floor`, `f64::round` and `f64::trunc` require `std`, these wrappers fall back to casting when `std`
+// is not available, which is accurate for the magnitudes dealt with in this crate
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn floor(value: f64) -> f64 {
+    value.floor()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn floor(value: f64) -> f64 {
+    let t = trunc(value);
+    if t > value {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn ceil(value: f64) -> f64 {
+    value.ceil()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn ceil(value: f64) -> f64 {
+    let t = trunc(value);
+    if t < value {
+        t + 1.0
+    } else {
+        t
+    }
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn round(value: f64) -> f64 {
+    value.round()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn round(value: f64) -> f64 {
+    // round half away from zero, as `f64::round` does
+    let t = trunc(value);
+    let diff = value - t;
+    if diff >= 0.5 {
+        t + 1.0
+    } else if diff <= -0.5 {
+        t - 1.0
+    } else {
+        t
+    }
+}
+
+#[cfg(feature = "std")]
+#[inline]
+pub(crate) fn trunc(value: f64) -> f64 {
+    value.trunc()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn trunc(value: f64) -> f64 {
+    value as i64 as f64
+}
@@ -0,0 +1,96 @@
+//! Modules for use with serde's `#[serde(with = "...")]` attribute, requires the `serde` feature.
+
+/// Serialize a [DateTime] as a unix timestamp in seconds rather than a string.
+///
+/// Datetimes which are a whole second are serialized as integers, otherwise as floats with microsecond
+/// precision. Both integers and floats are accepted when deserializing.
+///
+/// Values are always interpreted as seconds, unlike [DateTime::from_timestamp] large values are not taken to
+/// be milliseconds. As with [DateTime::timestamp], the timezone offset is ignored and is not preserved.
+///
+/// # Examples
+///
+/// ```
+/// use serde::{Deserialize, Serialize};
+/// use speedate::DateTime;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "speedate::serde::timestamp")]
+///     at: DateTime,
+/// }
+///
+/// let event = Event {
+///     at: DateTime::parse_str("2022-06-07T16:28:40").unwrap(),
+/// };
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(json, r#"{"at":1654619320}"#);
+///
+/// let event: Event = serde_json::from_str(r#"{"at":1654619320.5}"#).unwrap();
+/// assert_eq!(event.at.to_string(), "2022-06-07T16:28:40.500000");
+/// ```
+///
+/// [DateTime]: crate::DateTime
+/// [DateTime::from_timestamp]: crate::DateTime::from_timestamp
+/// [DateTime::timestamp]: crate::DateTime::timestamp
+pub mod timestamp {
+    use core::fmt;
+
+    use ::serde::de::{self, Visitor};
+    use ::serde::{Deserializer, Serializer};
+
+    use crate::numbers::{floor, round};
+    use crate::{Date, DateTime, ParseError, Time};
+
+    pub fn serialize<S: Serializer>(dt: &DateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        if dt.time.microsecond == 0 {
+            serializer.serialize_i64(dt.timestamp())
+        } else {
+            serializer.serialize_f64(dt.timestamp_f64())
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime, D::Error> {
+        deserializer.deserialize_any(TimestampVisitor)
+    }
+
+    fn from_seconds(second: i64, microsecond: u32) -> Result<DateTime, ParseError> {
+        let (date, time_second) = Date::from_timestamp_calc(second)?;
+        Ok(DateTime {
+            date,
+            time: Time::from_timestamp(time_second, microsecond)?,
+        })
+    }
+
+    struct TimestampVisitor;
+
+    impl Visitor<'_> for TimestampVisitor {
+        type Value = DateTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a unix timestamp in seconds")
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<DateTime, E> {
+            from_seconds(value, 0).map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<DateTime, E> {
+            let value = i64::try_from(value).map_err(|_| E::custom(ParseError::DateTooLarge))?;
+            self.visit_i64(value)
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<DateTime, E> {
+            if !value.is_finite() {
+                return Err(E::invalid_value(de::Unexpected::Float(value), &self));
+            }
+            let mut second = floor(value) as i64;
+            let mut microsecond = round((value - second as f64) * 1_000_000f64) as u32;
+            if microsecond >= 1_000_000 {
+                second += 1;
+                microsecond -= 1_000_000;
+            }
+            from_seconds(second, microsecond).map_err(E::custom)
+        }
+    }
+}
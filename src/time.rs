@@ -1,8 +1,11 @@
-use std::cmp::Ordering;
-use std::default::Default;
-use std::fmt;
-use std::str::FromStr;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
 
+use crate::date::MS_WATERSHED;
+use crate::numbers::{ceil, round, trunc};
 use crate::{get_digit, get_digit_unchecked, ConfigError, ParseError};
 
 /// A Time
@@ -13,6 +16,7 @@ use crate::{get_digit, get_digit_unchecked, ConfigError, ParseError};
 /// * `HH:MM`
 /// * `HH:MM:SSZ`
 /// * `HH:MM:SS.FFFFFFZ`
+/// * `HH` and `HHZ` - only when [TimeConfig::allow_hour_only] is set
 ///
 /// Fractions of a second are to microsecond precision, if the value contains greater
 /// precision, an error is raised.
@@ -22,7 +26,11 @@ use crate::{get_digit, get_digit_unchecked, ConfigError, ParseError};
 /// `Time` supports equality (`==`) and inequality (`>`, `<`, `>=`, `<=`) comparisons.
 ///
 /// See [Time::partial_cmp] for how this works.
-#[derive(Debug, PartialEq, Eq, Clone)]
+///
+/// # Default
+///
+/// The default `Time` is midnight without a timezone, `00:00:00`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct Time {
     /// Hour: 0 to 23
     pub hour: u8,
@@ -33,6 +41,8 @@ pub struct Time {
     /// microseconds: 0 to 999999
     pub microsecond: u32,
     /// timezone offset in seconds if provided, must be >-24h and <24h
+    ///
+    /// Setting this directly isn't validated, prefer [Time::set_timezone_offset]
     // This range is to match python,
     // Note: [Stack Overflow suggests](https://stackoverflow.com/a/8131056/949890) larger offsets can happen
     pub tz_offset: Option<i32>,
@@ -46,13 +56,13 @@ impl fmt::Display for Time {
             crate::display_num_buf(2, 3, self.minute as u32, &mut buf);
             crate::display_num_buf(2, 6, self.second as u32, &mut buf);
             crate::display_num_buf(6, 9, self.microsecond, &mut buf);
-            f.write_str(std::str::from_utf8(&buf[..]).unwrap())?
+            f.write_str(core::str::from_utf8(&buf[..]).unwrap())?
         } else {
             let mut buf: [u8; 8] = *b"00:00:00";
             crate::display_num_buf(2, 0, self.hour as u32, &mut buf);
             crate::display_num_buf(2, 3, self.minute as u32, &mut buf);
             crate::display_num_buf(2, 6, self.second as u32, &mut buf);
-            f.write_str(std::str::from_utf8(&buf[..]).unwrap())?
+            f.write_str(core::str::from_utf8(&buf[..]).unwrap())?
         }
         if let Some(tz_offset) = self.tz_offset {
             if tz_offset == 0 {
@@ -68,7 +78,7 @@ impl fmt::Display for Time {
                 }
                 crate::display_num_buf(2, 1, hours.unsigned_abs(), &mut buf);
                 crate::display_num_buf(2, 4, minutes.unsigned_abs(), &mut buf);
-                f.write_str(std::str::from_utf8(&buf[..]).unwrap())?;
+                f.write_str(core::str::from_utf8(&buf[..]).unwrap())?;
             }
         }
         Ok(())
@@ -141,6 +151,24 @@ impl PartialOrd for Time {
 }
 
 impl Time {
+    /// The earliest time, `00:00:00` with no timezone offset.
+    pub const MIN: Self = Self {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        microsecond: 0,
+        tz_offset: None,
+    };
+
+    /// The latest time, `23:59:59.999999` with no timezone offset.
+    pub const MAX: Self = Self {
+        hour: 23,
+        minute: 59,
+        second: 59,
+        microsecond: 999_999,
+        tz_offset: None,
+    };
+
     /// Parse a time from a string
     ///
     /// # Arguments
@@ -226,7 +254,8 @@ impl Time {
     /// ```
     #[inline]
     pub fn parse_bytes_with_config(bytes: &[u8], config: &TimeConfig) -> Result<Self, ParseError> {
-        Self::parse_bytes_offset(bytes, 0, config)
+        config.check_input_len(bytes)?;
+        Self::parse_bytes_offset(config.trim(bytes), 0, config)
     }
 
     /// Create a time from seconds and microseconds.
@@ -298,17 +327,38 @@ impl Time {
     }
 
     /// Parse a time from bytes with a starting index, extra characters at the end of the string result in an error
+    #[inline]
     pub(crate) fn parse_bytes_offset(bytes: &[u8], offset: usize, config: &TimeConfig) -> Result<Self, ParseError> {
+        Self::parse_bytes_offset_end_of_day(bytes, offset, config).map(|(time, _)| time)
+    }
+
+    /// As with `parse_bytes_offset` but also returns whether the time was `24:00:00` representing the end of
+    /// the day, see [TimeConfig::allow_end_of_day_24]
+    pub(crate) fn parse_bytes_offset_end_of_day(
+        bytes: &[u8],
+        offset: usize,
+        config: &TimeConfig,
+    ) -> Result<(Self, bool), ParseError> {
         let pure_time = PureTime::parse(bytes, offset, config)?;
 
         // Parse the timezone offset
         let mut tz_offset: Option<i32> = None;
         let mut position = pure_time.position;
+        if config.allow_space_before_offset && bytes.get(position) == Some(&b' ') && bytes.len() > position + 1 {
+            position += 1;
+        }
 
         if let Some(next_char) = bytes.get(position).copied() {
             position += 1;
             if next_char == b'Z' || next_char == b'z' {
                 tz_offset = Some(0);
+            } else if config.allow_tz_names && next_char.is_ascii_alphabetic() {
+                let name_len = bytes[position - 1..]
+                    .iter()
+                    .take_while(|c| c.is_ascii_alphabetic())
+                    .count();
+                tz_offset = Some(tz_name_offset(&bytes[position - 1..position - 1 + name_len])?);
+                position += name_len - 1;
             } else {
                 let sign = match next_char {
                     b'+' => 1,
@@ -329,34 +379,73 @@ impl Time {
                     _ => return Err(ParseError::InvalidCharTzSign),
                 };
 
-                let h1 = get_digit!(bytes, position, InvalidCharTzHour) as i32;
-                let h2 = get_digit!(bytes, position + 1, InvalidCharTzHour) as i32;
+                let decimal_hours = match config.allow_decimal_tz_offset {
+                    true => decimal_hours_offset(bytes, position)?,
+                    false => None,
+                };
+                let offset_val = if let Some((seconds, end)) = decimal_hours {
+                    position = end;
+                    sign * seconds
+                } else {
+                    let h1 = get_digit!(bytes, position, InvalidCharTzHour) as i32;
+                    let h2 = get_digit!(bytes, position + 1, InvalidCharTzHour) as i32;
 
-                let m1 = match bytes.get(position + 2) {
-                    Some(b':') => {
+                    let minute_seconds = match bytes.get(position + 2) {
+                        // e.g. `+02`, minutes are zero
+                        c if config.allow_hour_only_offset && !c.is_some_and(|c| *c == b':' || c.is_ascii_digit()) => {
+                            position += 2;
+                            0
+                        }
+                        c => {
+                            let m1 = match c {
+                                Some(b':') => {
+                                    position += 3;
+                                    get_digit!(bytes, position, InvalidCharTzMinute) as i32
+                                }
+                                Some(c) if c.is_ascii_digit() => {
+                                    position += 2;
+                                    (c - b'0') as i32
+                                }
+                                _ => return Err(ParseError::InvalidCharTzMinute),
+                            };
+                            let m2 = get_digit!(bytes, position + 1, InvalidCharTzMinute) as i32;
+
+                            let minute_seconds = m1 * 600 + m2 * 60;
+                            if minute_seconds >= 3600 {
+                                return Err(ParseError::OutOfRangeTzMinute);
+                            }
+                            position += 2;
+                            minute_seconds
+                        }
+                    };
+
+                    // seconds in offsets, e.g. `+00:09:21`, are only used by historical timezones
+                    let mut second = 0;
+                    if bytes.get(position) == Some(&b':') {
+                        if !config.allow_tz_offset_seconds {
+                            return Err(ParseError::TzOffsetSecondsNotAllowed);
+                        }
+                        let s1 = get_digit!(bytes, position + 1, InvalidCharTzSecond) as i32;
+                        let s2 = get_digit!(bytes, position + 2, InvalidCharTzSecond) as i32;
+                        second = s1 * 10 + s2;
+                        if second >= 60 {
+                            return Err(ParseError::OutOfRangeTzSecond);
+                        }
                         position += 3;
-                        get_digit!(bytes, position, InvalidCharTzMinute) as i32
-                    }
-                    Some(c) if c.is_ascii_digit() => {
-                        position += 2;
-                        (c - b'0') as i32
                     }
-                    _ => return Err(ParseError::InvalidCharTzMinute),
-                };
-                let m2 = get_digit!(bytes, position + 1, InvalidCharTzMinute) as i32;
-
-                let minute_seconds = m1 * 600 + m2 * 60;
-                if minute_seconds >= 3600 {
-                    return Err(ParseError::OutOfRangeTzMinute);
-                }
 
-                let offset_val = sign * (h1 * 36000 + h2 * 3600 + minute_seconds);
+                    sign * (h1 * 36000 + h2 * 3600 + minute_seconds + second)
+                };
                 // TZ must be less than 24 hours to match python
                 if offset_val.abs() >= 24 * 3600 {
                     return Err(ParseError::OutOfRangeTz);
                 }
                 tz_offset = Some(offset_val);
-                position += 2;
+            }
+
+            // e.g. `12:13:14+02:00Z`
+            if matches!(bytes.get(position), Some(b'Z' | b'z' | b'+' | b'-' | 226)) {
+                return Err(ParseError::DuplicateTimezone);
             }
         }
 
@@ -364,13 +453,16 @@ impl Time {
             return Err(ParseError::ExtraCharacters);
         }
 
-        Ok(Self {
-            hour: pure_time.hour,
-            minute: pure_time.minute,
-            second: pure_time.second,
-            microsecond: pure_time.microsecond,
-            tz_offset,
-        })
+        Ok((
+            Self {
+                hour: pure_time.hour,
+                minute: pure_time.minute,
+                second: pure_time.second,
+                microsecond: pure_time.microsecond,
+                tz_offset,
+            },
+            pure_time.end_of_day,
+        ))
     }
 
     /// Get the total seconds of the time
@@ -392,6 +484,123 @@ impl Time {
         total_seconds
     }
 
+    /// Seconds since midnight including the microseconds as a fraction, the timezone offset is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let d = Time::parse_str("12:13:14.5").unwrap();
+    /// assert_eq!(d.seconds_of_day_f64(), 43_994.5);
+    /// ```
+    pub fn seconds_of_day_f64(&self) -> f64 {
+        self.total_seconds() as f64 + self.microsecond as f64 / 1_000_000.0
+    }
+
+    /// Compare the wall clock time, ignoring `tz_offset`, unlike [Time::partial_cmp] which accounts for
+    /// timezone offsets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use speedate::Time;
+    ///
+    /// let t1 = Time::parse_str("12:00:00Z").unwrap();
+    /// let t2 = Time::parse_str("12:00:00+01:00").unwrap();
+    /// assert_eq!(t1.cmp_wall(&t2), Ordering::Equal);
+    /// assert!(t1 > t2);
+    /// ```
+    pub fn cmp_wall(&self, other: &Self) -> Ordering {
+        (self.hour, self.minute, self.second, self.microsecond).cmp(&(
+            other.hour,
+            other.minute,
+            other.second,
+            other.microsecond,
+        ))
+    }
+
+    /// Whether two times represent the same instant of the day, unlike `==` the timezone offsets don't need
+    /// to match, naïve times are treated as UTC.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let t1 = Time::parse_str("12:00:00Z").unwrap();
+    /// let t2 = Time::parse_str("13:00:00+01:00").unwrap();
+    /// assert!(t1.eq_instant(&t2));
+    /// ```
+    pub fn eq_instant(&self, other: &Self) -> bool {
+        let utc_seconds = |t: &Self| t.total_seconds() as i64 - t.tz_offset.unwrap_or(0) as i64;
+        utc_seconds(self) == utc_seconds(other) && self.microsecond == other.microsecond
+    }
+
+    /// Description of the format produced by `to_string()`, see [crate::Date::format_hint].
+    ///
+    /// The fraction is only included when microseconds aren't zero, and the timezone only when present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// assert_eq!(Time::format_hint(), "%H:%M:%S[.%f][Z|±HH:MM]");
+    /// ```
+    pub const fn format_hint() -> &'static str {
+        "%H:%M:%S[.%f][Z|±HH:MM]"
+    }
+
+    /// Like `to_string()`, but a zero timezone offset is rendered as `+00:00` rather than `Z`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let t = Time::parse_str("12:13:14Z").unwrap();
+    /// assert_eq!(t.to_string(), "12:13:14Z");
+    /// assert_eq!(t.to_string_z_as_offset(), "12:13:14+00:00");
+    /// ```
+    pub fn to_string_z_as_offset(&self) -> String {
+        let mut s = self.to_string();
+        if self.tz_offset == Some(0) {
+            // remove the trailing `Z`
+            s.pop();
+            s.push_str("+00:00");
+        }
+        s
+    }
+
+    /// Like `to_string()`, but trailing zeros are removed from the second fraction, giving the minimal
+    /// ISO 8601 form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let t = Time::parse_str("12:13:14.5Z").unwrap();
+    /// assert_eq!(t.to_string(), "12:13:14.500000Z");
+    /// assert_eq!(t.to_string_trimmed(), "12:13:14.5Z");
+    /// ```
+    pub fn to_string_trimmed(&self) -> String {
+        let mut s = self.to_string();
+        if self.microsecond != 0 {
+            let mut microsecond = self.microsecond;
+            let mut trailing_zeros = 0;
+            while microsecond.is_multiple_of(10) {
+                microsecond /= 10;
+                trailing_zeros += 1;
+            }
+            // the fraction is always 6 digits after `HH:MM:SS.`
+            s.replace_range(15 - trailing_zeros..15, "");
+        }
+        s
+    }
+
     /// Clone the time and set a new timezone offset.
     ///
     /// The returned time will represent a different point in time since the timezone offset is changed without
@@ -415,14 +624,116 @@ impl Time {
     /// assert_eq!(t2.to_string(), "12:13:14-08:00");
     /// ```
     pub fn with_timezone_offset(&self, tz_offset: Option<i32>) -> Result<Self, ParseError> {
+        let mut time = self.clone();
+        time.set_timezone_offset(tz_offset)?;
+        Ok(time)
+    }
+
+    /// Clone the time and set a new hour, `Err(ParseError::OutOfRangeHour)` is returned if `hour` is more than 23.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let t = Time::parse_str("12:13:14Z").unwrap();
+    /// assert_eq!(t.with_hour(2).unwrap().to_string(), "02:13:14Z");
+    /// ```
+    pub fn with_hour(&self, hour: u8) -> Result<Self, ParseError> {
+        if hour > 23 {
+            return Err(ParseError::OutOfRangeHour);
+        }
+        Ok(Self { hour, ..self.clone() })
+    }
+
+    /// Clone the time and set a new minute, `Err(ParseError::OutOfRangeMinute)` is returned if `minute` is more
+    /// than 59.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let t = Time::parse_str("12:13:14Z").unwrap();
+    /// assert_eq!(t.with_minute(0).unwrap().to_string(), "12:00:14Z");
+    /// ```
+    pub fn with_minute(&self, minute: u8) -> Result<Self, ParseError> {
+        if minute > 59 {
+            return Err(ParseError::OutOfRangeMinute);
+        }
+        Ok(Self { minute, ..self.clone() })
+    }
+
+    /// Clone the time and set a new second, `Err(ParseError::OutOfRangeSecond)` is returned if `second` is more
+    /// than 59.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let t = Time::parse_str("12:13:14Z").unwrap();
+    /// assert_eq!(t.with_second(59).unwrap().to_string(), "12:13:59Z");
+    /// ```
+    pub fn with_second(&self, second: u8) -> Result<Self, ParseError> {
+        if second > 59 {
+            return Err(ParseError::OutOfRangeSecond);
+        }
+        Ok(Self { second, ..self.clone() })
+    }
+
+    /// Clone the time and set a new microsecond, `Err(ParseError::SecondFractionTooLong)` is returned if
+    /// `microsecond` is not less than `1_000_000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::Time;
+    ///
+    /// let t = Time::parse_str("12:13:14.123456Z").unwrap();
+    /// assert_eq!(t.with_microsecond(0).unwrap().to_string(), "12:13:14Z");
+    /// ```
+    pub fn with_microsecond(&self, microsecond: u32) -> Result<Self, ParseError> {
+        if microsecond >= 1_000_000 {
+            return Err(ParseError::SecondFractionTooLong);
+        }
+        Ok(Self {
+            microsecond,
+            ..self.clone()
+        })
+    }
+
+    /// Set the timezone offset in place, like [Time::with_timezone_offset] the offset is validated and
+    /// `Err(ParseError::OutOfRangeTz)` is returned if `abs(tz_offset)` is not less than 24 hours, in which
+    /// case the time is unchanged.
+    ///
+    /// This should be preferred to setting `tz_offset` directly, which isn't validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use speedate::{ParseError, Time};
+    ///
+    /// let mut t = Time::parse_str("12:13:14Z").unwrap();
+    /// t.set_timezone_offset(Some(3600)).unwrap();
+    /// assert_eq!(t.to_string(), "12:13:14+01:00");
+    /// assert_eq!(t.set_timezone_offset(Some(86_400)), Err(ParseError::OutOfRangeTz));
+    /// assert_eq!(t.as_offset_seconds(), Some(3600));
+    /// ```
+    pub fn set_timezone_offset(&mut self, tz_offset: Option<i32>) -> Result<(), ParseError> {
         if let Some(offset_val) = tz_offset {
             if offset_val.abs() >= 24 * 3600 {
                 return Err(ParseError::OutOfRangeTz);
             }
         }
-        let mut time = self.clone();
-        time.tz_offset = tz_offset;
-        Ok(time)
+        self.tz_offset = tz_offset;
+        Ok(())
+    }
+
+    /// The timezone offset in seconds, `None` for a naïve time.
+    #[inline]
+    pub fn as_offset_seconds(&self) -> Option<i32> {
+        self.tz_offset
     }
 
     /// Create a new time in a different timezone.
@@ -471,18 +782,59 @@ pub(crate) struct PureTime {
     pub microsecond: u32,
     /// position of the cursor after parsing
     pub position: usize,
+    /// whether the time was `24:00:00`, in which case it's been normalised to `00:00:00`
+    pub end_of_day: bool,
 }
 
 impl PureTime {
     pub fn parse(bytes: &[u8], offset: usize, config: &TimeConfig) -> Result<Self, ParseError> {
+        if config.allow_space_padding {
+            if let Some(padded) = zero_space_padding(bytes, offset) {
+                return Self::parse(&padded, offset, config);
+            }
+        }
+        Self::parse_fields(bytes, offset, config).map_err(|e| whitespace_error(bytes, offset, e, config))
+    }
+
+    #[inline(always)]
+    fn parse_fields(bytes: &[u8], offset: usize, config: &TimeConfig) -> Result<Self, ParseError> {
+        if config.allow_hour_only {
+            // e.g. `12` or `12Z`
+            if let (
+                Some(h1 @ b'0'..=b'9'),
+                Some(h2 @ b'0'..=b'9'),
+                None | Some(b'Z' | b'z' | b'+' | b'-' | b' ' | 226),
+            ) = (bytes.get(offset), bytes.get(offset + 1), bytes.get(offset + 2))
+            {
+                let hour = (h1 - b'0') * 10 + (h2 - b'0');
+                Self::check_hour_minute(hour, 0, config)?;
+                return Self::build(hour, 0, 0, 0, offset + 2, config);
+            }
+        }
+        if config.allow_basic_format
+            && matches!(bytes.get(offset..offset + 4), Some(d) if d.iter().all(u8::is_ascii_digit))
+        {
+            return Self::parse_basic_format(bytes, offset, config);
+        }
+        if config.allow_single_digit_fields {
+            return Self::parse_single_digit_fields(bytes, offset, config);
+        }
         if bytes.len() - offset < 5 {
+            // e.g. `2:3`
+            if matches!(bytes.get(offset), Some(c) if c.is_ascii_digit()) && bytes.get(offset + 1) == Some(&b':') {
+                return Err(ParseError::TimeFieldNotPadded);
+            }
             return Err(ParseError::TooShort);
         }
         let hour: u8;
         let minute: u8;
         unsafe {
             let h1 = get_digit_unchecked!(bytes, offset, InvalidCharHour);
-            let h2 = get_digit_unchecked!(bytes, offset + 1, InvalidCharHour);
+            let h2 = match bytes.get_unchecked(offset + 1) {
+                c if c.is_ascii_digit() => c - b'0',
+                b':' => return Err(ParseError::TimeFieldNotPadded),
+                _ => return Err(ParseError::InvalidCharHour),
+            };
             hour = h1 * 10 + h2;
 
             match bytes.get_unchecked(offset + 2) {
@@ -490,77 +842,212 @@ impl PureTime {
                 _ => return Err(ParseError::InvalidCharTimeSep),
             }
             let m1 = get_digit_unchecked!(bytes, offset + 3, InvalidCharMinute);
-            let m2 = get_digit_unchecked!(bytes, offset + 4, InvalidCharMinute);
+            let m2 = match bytes.get_unchecked(offset + 4) {
+                c if c.is_ascii_digit() => c - b'0',
+                b':' => return Err(ParseError::TimeFieldNotPadded),
+                _ => return Err(ParseError::InvalidCharMinute),
+            };
             minute = m1 * 10 + m2;
         }
+        Self::check_hour_minute(hour, minute, config)?;
 
-        if hour > 23 {
-            return Err(ParseError::OutOfRangeHour);
-        }
+        let (second, microsecond, length) = match bytes.get(offset + 5) {
+            Some(b':') => {
+                let s1 = get_digit!(bytes, offset + 6, InvalidCharSecond);
+                let s2 = match bytes.get(offset + 7) {
+                    Some(c) if c.is_ascii_digit() => c - b'0',
+                    None | Some(b'.' | b',' | b'Z' | b'z' | b'+' | b'-') => return Err(ParseError::TimeFieldNotPadded),
+                    _ => return Err(ParseError::InvalidCharSecond),
+                };
+                Self::check_second(s1 * 10 + s2)?;
+                let (microsecond, fraction_length) = Self::parse_fraction(bytes, offset + 8, config)?;
+                (s1 * 10 + s2, microsecond, 8 + fraction_length)
+            }
+            Some(&c) if config.allow_fractional_minutes && config.fraction_separator.accepts(c) => {
+                let (minute_fraction, fraction_length) = Self::parse_fraction(bytes, offset + 5, config)?;
+                (0, minute_fraction_microseconds(minute_fraction), 5 + fraction_length)
+            }
+            _ => (0, 0, 5),
+        };
 
-        if minute > 59 {
-            return Err(ParseError::OutOfRangeMinute);
+        Self::build(hour, minute, second, microsecond, offset + length, config)
+    }
+
+    /// Parse an ISO 8601 basic format time, e.g. `1213` or `121314.5`, the caller has checked the first four
+    /// bytes are digits.
+    fn parse_basic_format(bytes: &[u8], offset: usize, config: &TimeConfig) -> Result<Self, ParseError> {
+        let hour = get_digit!(bytes, offset, InvalidCharHour) * 10 + get_digit!(bytes, offset + 1, InvalidCharHour);
+        let minute =
+            get_digit!(bytes, offset + 2, InvalidCharMinute) * 10 + get_digit!(bytes, offset + 3, InvalidCharMinute);
+        Self::check_hour_minute(hour, minute, config)?;
+        let (second, microsecond, length) = match bytes.get(offset + 4) {
+            Some(c) if c.is_ascii_digit() => {
+                let s2 = get_digit!(bytes, offset + 5, InvalidCharSecond);
+                Self::check_second((c - b'0') * 10 + s2)?;
+                let (microsecond, fraction_length) = Self::parse_fraction(bytes, offset + 6, config)?;
+                ((c - b'0') * 10 + s2, microsecond, 6 + fraction_length)
+            }
+            _ => (0, 0, 4),
+        };
+        Self::build(hour, minute, second, microsecond, offset + length, config)
+    }
+
+    /// Parse a time where hours, minutes and seconds may be either one or two digits, e.g. `2:3:4`.
+    fn parse_single_digit_fields(bytes: &[u8], offset: usize, config: &TimeConfig) -> Result<Self, ParseError> {
+        if bytes.len() - offset < 3 {
+            return Err(ParseError::TooShort);
+        }
+        let (hour, mut position) = parse_one_or_two_digits(bytes, offset, ParseError::InvalidCharHour)?;
+        match bytes.get(position) {
+            Some(b':') => (),
+            _ => return Err(ParseError::InvalidCharTimeSep),
         }
+        let minute;
+        (minute, position) = parse_one_or_two_digits(bytes, position + 1, ParseError::InvalidCharMinute)?;
+        Self::check_hour_minute(hour, minute, config)?;
 
-        let mut length: usize = 5;
-        let (second, microsecond) = match bytes.get(offset + 5) {
+        let (second, microsecond) = match bytes.get(position) {
             Some(b':') => {
-                let s1 = get_digit!(bytes, offset + 6, InvalidCharSecond);
-                let s2 = get_digit!(bytes, offset + 7, InvalidCharSecond);
-                let second = s1 * 10 + s2;
-                if second > 59 {
-                    return Err(ParseError::OutOfRangeSecond);
-                }
-                length = 8;
-
-                let mut microsecond = 0;
-                let frac_sep = bytes.get(offset + 8).copied();
-                if frac_sep == Some(b'.') || frac_sep == Some(b',') {
-                    length = 9;
-                    let mut i: usize = 0;
-                    loop {
-                        match bytes.get(offset + length + i) {
-                            Some(c) if c.is_ascii_digit() => {
-                                // If we've passed `i=6` then we are "truncating" the extra precision
-                                // The easiest way to do this is to simply no-op and continue the loop
-                                if i < 6 {
-                                    microsecond *= 10;
-                                    microsecond += (c - b'0') as u32;
-                                }
-                            }
-                            _ => {
-                                break;
-                            }
-                        }
-                        i += 1;
-                        if i > 6 {
-                            match config.microseconds_precision_overflow_behavior {
-                                MicrosecondsPrecisionOverflowBehavior::Truncate => continue,
-                                MicrosecondsPrecisionOverflowBehavior::Error => {
-                                    return Err(ParseError::SecondFractionTooLong)
-                                }
-                            }
-                        }
+                let second;
+                (second, position) = parse_one_or_two_digits(bytes, position + 1, ParseError::InvalidCharSecond)?;
+                Self::check_second(second)?;
+                let (microsecond, fraction_length) = Self::parse_fraction(bytes, position, config)?;
+                position += fraction_length;
+                (second, microsecond)
+            }
+            Some(&c) if config.allow_fractional_minutes && config.fraction_separator.accepts(c) => {
+                let (minute_fraction, fraction_length) = Self::parse_fraction(bytes, position, config)?;
+                position += fraction_length;
+                (0, minute_fraction_microseconds(minute_fraction))
+            }
+            _ => (0, 0),
+        };
+
+        Self::build(hour, minute, second, microsecond, position, config)
+    }
+
+    /// Parse an optional second fraction starting with `.` or `,` (as permitted by `fraction_separator`)
+    /// at `position`, returns the microseconds and the number of bytes consumed.
+    ///
+    /// With [MicrosecondsPrecisionOverflowBehavior::Round] the microseconds may be `1_000_000`, this is carried
+    /// into the seconds by `PureTime::build`.
+    #[inline(always)]
+    fn parse_fraction(bytes: &[u8], position: usize, config: &TimeConfig) -> Result<(u32, usize), ParseError> {
+        let mut microsecond = 0;
+        let mut round_up = false;
+        match bytes.get(position) {
+            Some(&c) if config.fraction_separator.accepts(c) => (),
+            _ => return Ok((0, 0)),
+        }
+        let mut i: usize = 0;
+        loop {
+            match bytes.get(position + 1 + i) {
+                Some(c) if c.is_ascii_digit() => {
+                    // If we've passed `i=6` then we are "truncating" the extra precision
+                    // The easiest way to do this is to simply no-op and continue the loop
+                    if i < 6 {
+                        microsecond *= 10;
+                        microsecond += (c - b'0') as u32;
+                    } else if i == 6 {
+                        round_up = *c >= b'5';
                     }
-                    if i == 0 {
-                        return Err(ParseError::SecondFractionMissing);
+                }
+                _ => {
+                    break;
+                }
+            }
+            i += 1;
+            if i > 6 {
+                match config.microseconds_precision_overflow_behavior {
+                    MicrosecondsPrecisionOverflowBehavior::Truncate | MicrosecondsPrecisionOverflowBehavior::Round => {
+                        continue
                     }
-                    if i < 6 {
-                        microsecond *= 10_u32.pow(6 - i as u32);
+                    MicrosecondsPrecisionOverflowBehavior::Error => return Err(ParseError::SecondFractionTooLong),
+                }
+            }
+        }
+        if i == 0 {
+            return Err(ParseError::SecondFractionMissing);
+        }
+        if i < 6 {
+            microsecond *= 10_u32.pow(6 - i as u32);
+        } else if round_up
+            && config.microseconds_precision_overflow_behavior == MicrosecondsPrecisionOverflowBehavior::Round
+        {
+            microsecond += 1;
+        }
+        Ok((microsecond, 1 + i))
+    }
+
+    /// Check the hour and minute are in range, called as soon as they're read so out of range values are
+    /// reported before any later invalid characters.
+    #[inline(always)]
+    fn check_hour_minute(hour: u8, minute: u8, config: &TimeConfig) -> Result<(), ParseError> {
+        if hour > 24 || (hour == 24 && !config.allow_end_of_day_24) {
+            return Err(ParseError::OutOfRangeHour);
+        }
+        if minute > 59 {
+            return Err(ParseError::OutOfRangeMinute);
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn check_second(second: u8) -> Result<(), ParseError> {
+        if second > 59 {
+            return Err(ParseError::OutOfRangeSecond);
+        }
+        Ok(())
+    }
+
+    /// Build the `PureTime` from fields already range checked, whole seconds in `microsecond` are carried
+    /// into the seconds, minutes and hours.
+    ///
+    /// A carry past `23:59:59` is the end of the day if `allow_end_of_day_24` is set, otherwise it's an error.
+    #[inline(always)]
+    fn build(
+        mut hour: u8,
+        mut minute: u8,
+        mut second: u8,
+        mut microsecond: u32,
+        position: usize,
+        config: &TimeConfig,
+    ) -> Result<Self, ParseError> {
+        // `24:00:00` is only valid as exactly the end of the day
+        if hour == 24 && (minute != 0 || second != 0 || microsecond != 0) {
+            return Err(ParseError::OutOfRangeHour);
+        }
+
+        if microsecond >= 1_000_000 {
+            second += (microsecond / 1_000_000) as u8;
+            microsecond %= 1_000_000;
+            if second > 59 {
+                second -= 60;
+                minute += 1;
+                if minute > 59 {
+                    if hour == 23 && !config.allow_end_of_day_24 {
+                        // `23:59:59.9999999` can't be rounded up without `24:00:00`
+                        return Err(ParseError::TimeTooLarge);
                     }
-                    length += i;
+                    minute = 0;
+                    // `23:59:59.9999999` rounded up is the end of the day
+                    hour += 1;
                 }
-                (second, microsecond)
             }
-            _ => (0, 0),
-        };
+        }
+
+        let end_of_day = hour == 24;
+        if end_of_day {
+            hour = 0;
+        }
 
         Ok(Self {
             hour,
             minute,
             second,
             microsecond,
-            position: offset + length,
+            position,
+            end_of_day,
         })
     }
 
@@ -569,11 +1056,132 @@ impl PureTime {
     }
 }
 
+/// Timezone names and their offsets in seconds, only names with a fixed offset are included
+pub(crate) const TZ_NAMES: [(&str, i32); 3] = [("UTC", 0), ("UT", 0), ("GMT", 0)];
+
+/// Find the offset of a timezone name from `TZ_NAMES`, matching is case-insensitive.
+pub(crate) fn tz_name_offset(name: &[u8]) -> Result<i32, ParseError> {
+    TZ_NAMES
+        .iter()
+        .find(|(tz_name, _)| tz_name.as_bytes().eq_ignore_ascii_case(name))
+        .map(|(_, offset)| *offset)
+        .ok_or(ParseError::UnknownTzName)
+}
+
+/// Replace invalid character errors with `UnexpectedWhitespace` where the invalid character is whitespace,
+/// e.g. `12 :13:14`, to give a clearer error.
+#[cold]
+fn whitespace_error(bytes: &[u8], offset: usize, error: ParseError, config: &TimeConfig) -> ParseError {
+    match error_position(bytes, offset, &error, config).and_then(|position| bytes.get(position)) {
+        Some(c) if c.is_ascii_whitespace() => ParseError::UnexpectedWhitespace,
+        _ => error,
+    }
+}
+
+/// Position of the byte which caused an invalid hour, minute, second or separator error, found by walking
+/// the fields as `PureTime::parse_fields` reads them, `None` for other errors.
+fn error_position(bytes: &[u8], offset: usize, error: &ParseError, config: &TimeConfig) -> Option<usize> {
+    // basic format is only used when the first four bytes are digits, then there are no separators
+    let separators = !(config.allow_basic_format
+        && matches!(bytes.get(offset..offset + 4), Some(d) if d.iter().all(u8::is_ascii_digit)));
+    let mut position = offset;
+    for (index, field_error) in [
+        ParseError::InvalidCharHour,
+        ParseError::InvalidCharMinute,
+        ParseError::InvalidCharSecond,
+    ]
+    .iter()
+    .enumerate()
+    {
+        if index > 0 && separators {
+            if bytes.get(position) != Some(&b':') {
+                return (*error == ParseError::InvalidCharTimeSep).then_some(position);
+            }
+            position += 1;
+        }
+        let field = bytes.get(position..).unwrap_or_default();
+        let width = if config.allow_single_digit_fields && separators {
+            field.iter().take(2).take_while(|c| c.is_ascii_digit()).count().max(1)
+        } else {
+            2
+        };
+        match field.iter().take(width).position(|c| !c.is_ascii_digit()) {
+            Some(invalid) => return (error == field_error).then_some(position + invalid),
+            None => position += width,
+        }
+    }
+    None
+}
+
+/// Copy of `bytes` with spaces padding the hour, minute and second replaced by zeros, e.g. ` 9: 5: 3` becomes
+/// `09:05:03`, `None` if there are no such spaces.
+fn zero_space_padding(bytes: &[u8], offset: usize) -> Option<Vec<u8>> {
+    let mut padded: Option<Vec<u8>> = None;
+    for position in [offset, offset + 3, offset + 6] {
+        let field_start = position == offset || bytes.get(position - 1) == Some(&b':');
+        if field_start && bytes.get(position) == Some(&b' ') && bytes.get(position + 1).is_some_and(u8::is_ascii_digit)
+        {
+            padded.get_or_insert_with(|| bytes.to_vec())[position] = b'0';
+        } else if !field_start {
+            break;
+        }
+    }
+    padded
+}
+
+/// Parse an offset in decimal hours such as `5.5` at `position`, returns the offset in seconds rounded to the
+/// nearest second and the position after the offset, or `None` if the hours aren't followed by `.`.
+fn decimal_hours_offset(bytes: &[u8], position: usize) -> Result<Option<(i32, usize)>, ParseError> {
+    let (hours, mut position) = match parse_one_or_two_digits(bytes, position, ParseError::InvalidCharTzHour) {
+        Ok((hours, position)) if bytes.get(position) == Some(&b'.') => (hours, position + 1),
+        _ => return Ok(None),
+    };
+    let mut fraction: u64 = 0;
+    let mut denominator: u64 = 1;
+    let fraction_start = position;
+    while let Some(c) = bytes.get(position).filter(|c| c.is_ascii_digit()) {
+        // digits after the ninth can't change the number of seconds once rounded
+        if denominator < 1_000_000_000 {
+            fraction = fraction * 10 + (c - b'0') as u64;
+            denominator *= 10;
+        }
+        position += 1;
+    }
+    if position == fraction_start {
+        return Err(ParseError::InvalidCharTzMinute);
+    }
+    let fraction_seconds = (fraction * 3600 + denominator / 2) / denominator;
+    Ok(Some((hours as i32 * 3600 + fraction_seconds as i32, position)))
+}
+
+/// Convert a fraction of a minute in millionths, as returned by `PureTime::parse_fraction`, to microseconds.
+#[inline]
+fn minute_fraction_microseconds(minute_fraction: u32) -> u32 {
+    minute_fraction * 60
+}
+
+/// Parse a one or two digit number at `position`, returns the value and the position after the digits.
+#[inline]
+fn parse_one_or_two_digits(bytes: &[u8], position: usize, error: ParseError) -> Result<(u8, usize), ParseError> {
+    let first = match bytes.get(position) {
+        Some(c) if c.is_ascii_digit() => c - b'0',
+        _ => return Err(error),
+    };
+    match bytes.get(position + 1) {
+        Some(c) if c.is_ascii_digit() => Ok((first * 10 + (c - b'0'), position + 2)),
+        _ => Ok((first, position + 1)),
+    }
+}
+
 #[derive(Debug, Clone, Default, Copy, PartialEq)]
 pub enum MicrosecondsPrecisionOverflowBehavior {
     Truncate,
     #[default]
     Error,
+    /// Round to the nearest microsecond using the 7th digit of the fraction, with halves rounded up,
+    /// `23:59:59.9999999` rounds up to the end of the day with [TimeConfig::allow_end_of_day_24], otherwise
+    /// it's rejected with [ParseError::TimeTooLarge]
+    Round,
 }
 
 impl TryFrom<&str> for MicrosecondsPrecisionOverflowBehavior {
@@ -582,27 +1190,204 @@ impl TryFrom<&str> for MicrosecondsPrecisionOverflowBehavior {
         match value.to_lowercase().as_str() {
             "truncate" => Ok(Self::Truncate),
             "error" => Ok(Self::Error),
+            "round" => Ok(Self::Round),
             _ => Err(ConfigError::UnknownMicrosecondsPrecisionOverflowBehaviorString),
         }
     }
 }
 
+/// How the fractional microseconds of a float timestamp are rounded to a whole number of microseconds
+#[derive(Debug, Clone, Default, Copy, PartialEq)]
+pub enum RoundingMode {
+    /// Round to the nearest microsecond, with halves rounded up
+    #[default]
+    Round,
+    /// Round down to the microsecond, towards the start of the second
+    Truncate,
+    /// Round up to the microsecond
+    Ceil,
+}
+
+impl TryFrom<&str> for RoundingMode {
+    type Error = ConfigError;
+    fn try_from(value: &str) -> Result<Self, ConfigError> {
+        match value.to_lowercase().as_str() {
+            "round" => Ok(Self::Round),
+            "truncate" => Ok(Self::Truncate),
+            "ceil" => Ok(Self::Ceil),
+            _ => Err(ConfigError::UnknownRoundingModeString),
+        }
+    }
+}
+
+impl RoundingMode {
+    pub(crate) fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Round => round(value),
+            Self::Truncate => trunc(value),
+            Self::Ceil => ceil(value),
+        }
+    }
+}
+
+/// Which characters may separate seconds from their fraction
+#[derive(Debug, Clone, Default, Copy, PartialEq)]
+pub enum FractionSep {
+    /// Either `.` or `,`, as permitted by ISO 8601
+    #[default]
+    Either,
+    /// Only `.`, e.g. `12:13:14,5` is rejected
+    DotOnly,
+    /// Only `,` as used in many European locales, e.g. `12:13:14.5` is rejected
+    CommaOnly,
+}
+
+impl FractionSep {
+    pub(crate) fn accepts(self, c: u8) -> bool {
+        match self {
+            Self::Either => c == b'.' || c == b',',
+            Self::DotOnly => c == b'.',
+            Self::CommaOnly => c == b',',
+        }
+    }
+}
+
+/// How unix timestamps are interpreted as seconds or milliseconds
+#[derive(Debug, Clone, Default, Copy, PartialEq)]
+pub enum TimestampUnit {
+    /// Timestamps with an absolute value above `2e10` are in milliseconds, see [crate::DateTime::from_timestamp]
+    #[default]
+    Infer,
+    /// Timestamps with an absolute value above `2e10` are only in milliseconds if they have at least 13 digits
+    /// and so would be out of range in seconds, other timestamps above `2e10` such as `253402300799` could be
+    /// either and are rejected with [ParseError::AmbiguousTimestamp]
+    InferStrict,
+}
+
+impl TimestampUnit {
+    /// Whether a timestamp with the absolute value `abs_timestamp` is in milliseconds.
+    pub(crate) fn is_milliseconds(self, abs_timestamp: f64) -> Result<bool, ParseError> {
+        if abs_timestamp <= MS_WATERSHED as f64 {
+            return Ok(false);
+        }
+        match self {
+            Self::Infer => Ok(true),
+            // seconds between 0000 and 9999 have at most 12 digits, so 13 digits must be milliseconds
+            Self::InferStrict if abs_timestamp >= 1e12 => Ok(true),
+            Self::InferStrict => Err(ParseError::AmbiguousTimestamp),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct TimeConfig {
     pub microseconds_precision_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
     pub unix_timestamp_offset: Option<i32>,
+    /// Accept `24:00:00` as the end of the day as permitted by ISO 8601. For [Time] this is normalised to
+    /// `00:00:00`, for [crate::DateTime] the date is advanced to the following day.
+    pub allow_end_of_day_24: bool,
+    /// How microseconds are rounded when parsing float timestamps
+    pub float_rounding: RoundingMode,
+    /// Accept hours, minutes and seconds of a single digit, e.g. `2:3:4` is parsed as `02:03:04`, when `false`
+    /// such times are rejected with [ParseError::TimeFieldNotPadded].
+    pub allow_single_digit_fields: bool,
+    /// Ignore leading and trailing ASCII whitespace (e.g. space, tab, `\r` and `\n`) when parsing.
+    pub trim_whitespace: bool,
+    /// Allow a single space between the time and the timezone offset, e.g. `12:13:14 +02:00`.
+    pub allow_space_before_offset: bool,
+    /// Accept the timezone names `UTC`, `UT` and `GMT` in place of an offset, e.g. `12:13:14UTC`.
+    pub allow_tz_names: bool,
+    /// Accept seconds in timezone offsets, e.g. `+00:09:21`, when `false` such offsets are rejected with
+    /// [ParseError::TzOffsetSecondsNotAllowed].
+    pub allow_tz_offset_seconds: bool,
+    /// Accept a fraction of a minute in place of seconds, e.g. `12:13.5` is parsed as `12:13:30`.
+    pub allow_fractional_minutes: bool,
+    /// Accept a two digit hour without minutes or seconds, optionally followed by a timezone, e.g. `12Z` is
+    /// parsed as `12:00:00Z`.
+    pub allow_hour_only: bool,
+    /// Accept unix timestamps prefixed with a single `@`, e.g. `@1654646400` as used by Logstash.
+    pub allow_timestamp_at_prefix: bool,
+    /// Accept a space in place of a leading zero in the hour, minute and second as used in fixed-width
+    /// files, e.g. ` 9: 5:03` is parsed as `09:05:03`.
+    pub allow_space_padding: bool,
+    /// When set, inputs longer than this are rejected with [ParseError::InputTooLong] before any parsing, to
+    /// limit the work done on untrusted input.
+    pub max_input_len: Option<usize>,
+    /// Characters accepted between the seconds and their fraction, by default either `.` or `,`.
+    pub fraction_separator: FractionSep,
+    /// Accept timezone offsets in decimal hours, e.g. `+5.5` is parsed as `+05:30`, alongside `±HH:MM`.
+    pub allow_decimal_tz_offset: bool,
+    /// Accept ISO 8601 basic format times without `:` separators, e.g. `121314` or `1213`, a fraction and
+    /// timezone may follow as usual, e.g. `121314.5Z`.
+    pub allow_basic_format: bool,
+    /// Ignore a leading UTF-8 byte order mark (`\u{FEFF}`, bytes `EF BB BF`), as prepended to files by some
+    /// Windows tools.
+    pub skip_bom: bool,
+    /// Accept timezone offsets with only hours, e.g. `+02` is parsed as `+02:00`.
+    pub allow_hour_only_offset: bool,
+    /// How unix timestamps are interpreted as seconds or milliseconds.
+    pub timestamp_unit: TimestampUnit,
+    /// Accept a datetime with no separator between the date and time when the time starts with a digit, e.g.
+    /// `2020-01-0112:13:14`. This is nonstandard and only intended for known malformed input, the date is
+    /// always taken as the first 10 bytes so other flags changing the time format such as
+    /// `allow_basic_format` and `allow_single_digit_fields` apply to whatever follows, e.g. with
+    /// `allow_basic_format`, `2020-01-01121314` is also accepted.
+    pub allow_missing_datetime_sep: bool,
 }
 
 impl TimeConfig {
     pub fn builder() -> TimeConfigBuilder {
         TimeConfigBuilder::new()
     }
+
+    /// Check `bytes` isn't longer than `max_input_len`.
+    #[inline]
+    pub(crate) fn check_input_len(&self, bytes: &[u8]) -> Result<(), ParseError> {
+        match self.max_input_len {
+            Some(max_input_len) if bytes.len() > max_input_len => Err(ParseError::InputTooLong),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove a leading byte order mark from `bytes` if `skip_bom` is set, then leading and trailing whitespace
+    /// if `trim_whitespace` is set.
+    #[inline]
+    pub(crate) fn trim<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        let bytes = match bytes {
+            [0xEF, 0xBB, 0xBF, rest @ ..] if self.skip_bom => rest,
+            bytes => bytes,
+        };
+        if self.trim_whitespace {
+            bytes.trim_ascii()
+        } else {
+            bytes
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct TimeConfigBuilder {
     microseconds_precision_overflow_behavior: Option<MicrosecondsPrecisionOverflowBehavior>,
     unix_timestamp_offset: Option<i32>,
+    allow_end_of_day_24: bool,
+    float_rounding: Option<RoundingMode>,
+    allow_single_digit_fields: bool,
+    trim_whitespace: bool,
+    allow_space_before_offset: bool,
+    allow_tz_names: bool,
+    allow_tz_offset_seconds: bool,
+    allow_fractional_minutes: bool,
+    allow_hour_only: bool,
+    allow_timestamp_at_prefix: bool,
+    allow_space_padding: bool,
+    max_input_len: Option<usize>,
+    fraction_separator: Option<FractionSep>,
+    allow_decimal_tz_offset: bool,
+    allow_basic_format: bool,
+    skip_bom: bool,
+    allow_hour_only_offset: bool,
+    timestamp_unit: Option<TimestampUnit>,
+    allow_missing_datetime_sep: bool,
 }
 
 impl TimeConfigBuilder {
@@ -620,10 +1405,105 @@ impl TimeConfigBuilder {
         self.unix_timestamp_offset = unix_timestamp_offset;
         self
     }
+    pub fn allow_end_of_day_24(mut self, allow_end_of_day_24: bool) -> Self {
+        self.allow_end_of_day_24 = allow_end_of_day_24;
+        self
+    }
+    pub fn float_rounding(mut self, float_rounding: RoundingMode) -> Self {
+        self.float_rounding = Some(float_rounding);
+        self
+    }
+    pub fn allow_single_digit_fields(mut self, allow_single_digit_fields: bool) -> Self {
+        self.allow_single_digit_fields = allow_single_digit_fields;
+        self
+    }
+    pub fn trim_whitespace(mut self, trim_whitespace: bool) -> Self {
+        self.trim_whitespace = trim_whitespace;
+        self
+    }
+    pub fn allow_space_before_offset(mut self, allow_space_before_offset: bool) -> Self {
+        self.allow_space_before_offset = allow_space_before_offset;
+        self
+    }
+    pub fn allow_tz_names(mut self, allow_tz_names: bool) -> Self {
+        self.allow_tz_names = allow_tz_names;
+        self
+    }
+    pub fn allow_tz_offset_seconds(mut self, allow_tz_offset_seconds: bool) -> Self {
+        self.allow_tz_offset_seconds = allow_tz_offset_seconds;
+        self
+    }
+    pub fn allow_fractional_minutes(mut self, allow_fractional_minutes: bool) -> Self {
+        self.allow_fractional_minutes = allow_fractional_minutes;
+        self
+    }
+    pub fn allow_hour_only(mut self, allow_hour_only: bool) -> Self {
+        self.allow_hour_only = allow_hour_only;
+        self
+    }
+    pub fn allow_timestamp_at_prefix(mut self, allow_timestamp_at_prefix: bool) -> Self {
+        self.allow_timestamp_at_prefix = allow_timestamp_at_prefix;
+        self
+    }
+    pub fn allow_space_padding(mut self, allow_space_padding: bool) -> Self {
+        self.allow_space_padding = allow_space_padding;
+        self
+    }
+    pub fn max_input_len(mut self, max_input_len: Option<usize>) -> Self {
+        self.max_input_len = max_input_len;
+        self
+    }
+    pub fn fraction_separator(mut self, fraction_separator: FractionSep) -> Self {
+        self.fraction_separator = Some(fraction_separator);
+        self
+    }
+    pub fn allow_decimal_tz_offset(mut self, allow_decimal_tz_offset: bool) -> Self {
+        self.allow_decimal_tz_offset = allow_decimal_tz_offset;
+        self
+    }
+    pub fn allow_basic_format(mut self, allow_basic_format: bool) -> Self {
+        self.allow_basic_format = allow_basic_format;
+        self
+    }
+    pub fn skip_bom(mut self, skip_bom: bool) -> Self {
+        self.skip_bom = skip_bom;
+        self
+    }
+    pub fn allow_hour_only_offset(mut self, allow_hour_only_offset: bool) -> Self {
+        self.allow_hour_only_offset = allow_hour_only_offset;
+        self
+    }
+    pub fn timestamp_unit(mut self, timestamp_unit: TimestampUnit) -> Self {
+        self.timestamp_unit = Some(timestamp_unit);
+        self
+    }
+    pub fn allow_missing_datetime_sep(mut self, allow_missing_datetime_sep: bool) -> Self {
+        self.allow_missing_datetime_sep = allow_missing_datetime_sep;
+        self
+    }
     pub fn build(self) -> TimeConfig {
         TimeConfig {
             microseconds_precision_overflow_behavior: self.microseconds_precision_overflow_behavior.unwrap_or_default(),
             unix_timestamp_offset: self.unix_timestamp_offset,
+            allow_end_of_day_24: self.allow_end_of_day_24,
+            float_rounding: self.float_rounding.unwrap_or_default(),
+            allow_single_digit_fields: self.allow_single_digit_fields,
+            trim_whitespace: self.trim_whitespace,
+            allow_space_before_offset: self.allow_space_before_offset,
+            allow_tz_names: self.allow_tz_names,
+            allow_tz_offset_seconds: self.allow_tz_offset_seconds,
+            allow_fractional_minutes: self.allow_fractional_minutes,
+            allow_hour_only: self.allow_hour_only,
+            allow_timestamp_at_prefix: self.allow_timestamp_at_prefix,
+            allow_space_padding: self.allow_space_padding,
+            max_input_len: self.max_input_len,
+            fraction_separator: self.fraction_separator.unwrap_or_default(),
+            allow_decimal_tz_offset: self.allow_decimal_tz_offset,
+            allow_basic_format: self.allow_basic_format,
+            skip_bom: self.skip_bom,
+            allow_hour_only_offset: self.allow_hour_only_offset,
+            timestamp_unit: self.timestamp_unit.unwrap_or_default(),
+            allow_missing_datetime_sep: self.allow_missing_datetime_sep,
         }
     }
 }
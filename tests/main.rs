@@ -1,13 +1,19 @@
 use std::fs::File;
+#[cfg(feature = "std")]
+use std::io::Cursor;
 use std::io::Read;
 use std::str::FromStr;
 
-use chrono::{Datelike, FixedOffset as ChronoFixedOffset, NaiveDate, NaiveDateTime, Timelike, Utc as ChronoUtc};
+use chrono::{DateTime as ChronoDateTime, Datelike, NaiveDate, NaiveDateTime, Timelike};
+#[cfg(feature = "std")]
+use chrono::{FixedOffset as ChronoFixedOffset, Utc as ChronoUtc};
 use strum::EnumMessage;
 
 use speedate::{
-    float_parse_bytes, float_parse_str, int_parse_bytes, int_parse_str, Date, DateTime, Duration, IntFloat,
-    MicrosecondsPrecisionOverflowBehavior, ParseError, Time, TimeConfig, TimeConfigBuilder,
+    duration_max, duration_min, float_parse_bytes, float_parse_str, int_parse_bytes, int_parse_str, CalendarDelta,
+    Date, DateConfig, DateConfigBuilder, DateFieldOrder, DateTime, Duration, DurationConfig, DurationConfigBuilder,
+    FractionSep, IntFloat, MicrosecondsPrecisionOverflowBehavior, ParseError, ParseErrorWithInput, RoundingMode, Time,
+    TimeConfig, TimeConfigBuilder, TimestampUnit, TzSource, WeekStart, ZonedDateTime,
 };
 
 /// macro for expected values
@@ -84,6 +90,13 @@ fn date() {
     assert_eq!(format!("{d:?}"), "Date { year: 2020, month: 1, day: 1 }");
 }
 
+#[test]
+fn date_default() {
+    let d = Date::default();
+    assert_eq!(d.to_string(), "1970-01-01");
+    assert_eq!(d.timestamp(), 0);
+}
+
 #[test]
 fn date_bytes_err() {
     // https://github.com/python/cpython/blob/5849af7a80166e9e82040e082f22772bd7cf3061/Lib/test/datetimetester.py#L3237
@@ -151,6 +164,349 @@ param_tests! {
     date_unix_too_low: err => "-62167219200001", DateTooSmall;
 }
 
+#[test]
+fn date_two_digit_year_pivot() {
+    let config = DateConfigBuilder::new().two_digit_year_pivot(Some(50)).build();
+    let d = Date::parse_bytes_with_config(b"20-01-01", &config).unwrap();
+    assert_eq!(d.to_string(), "2020-01-01");
+    let d = Date::parse_bytes_with_config(b"99-01-01", &config).unwrap();
+    assert_eq!(d.to_string(), "1999-01-01");
+    let d = Date::parse_bytes_with_config(b"49-12-31", &config).unwrap();
+    assert_eq!(d.to_string(), "2049-12-31");
+    let d = Date::parse_bytes_with_config(b"50-01-01", &config).unwrap();
+    assert_eq!(d.to_string(), "1950-01-01");
+
+    // four digit years are still supported
+    let d = Date::parse_bytes_with_config(b"2020-01-01", &config).unwrap();
+    assert_eq!(d.to_string(), "2020-01-01");
+
+    // leap years are checked against the expanded year
+    let d = Date::parse_bytes_with_config(b"00-02-29", &config).unwrap();
+    assert_eq!(d.to_string(), "2000-02-29");
+    assert_eq!(
+        Date::parse_bytes_with_config(b"01-02-29", &config).unwrap_err(),
+        ParseError::OutOfRangeDay
+    );
+    assert_eq!(
+        Date::parse_bytes_rfc3339_with_config(b"20-01-01x", &config).unwrap_err(),
+        ParseError::ExtraCharacters
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"20-01-0", &config).unwrap_err(),
+        ParseError::TooShort
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"2x-01-01", &config).unwrap_err(),
+        ParseError::InvalidCharYear
+    );
+}
+
+#[test]
+fn date_named_month() {
+    for s in [
+        "2020-Jan-01",
+        "2020-January-01",
+        "2020-jan-01",
+        "2020-JANUARY-01",
+        "01 January 2020",
+        "1 January 2020",
+        "01 Jan 2020",
+    ] {
+        assert_eq!(
+            Date::parse_named_month_str(s).unwrap().to_string(),
+            "2020-01-01",
+            "{s:?}"
+        );
+    }
+    assert_eq!(
+        Date::parse_named_month_str("2020-Sep-30").unwrap().to_string(),
+        "2020-09-30"
+    );
+    assert_eq!(
+        Date::parse_named_month_str("2020-May-31").unwrap().to_string(),
+        "2020-05-31"
+    );
+    assert_eq!(
+        Date::parse_named_month_bytes(b"29 February 2024").unwrap().to_string(),
+        "2024-02-29"
+    );
+    for (input, error) in [
+        ("2020-Foo-01", ParseError::InvalidMonthName),
+        ("2020-Janu-01", ParseError::InvalidMonthName),
+        ("2020-01-01", ParseError::InvalidMonthName),
+        ("01 Smarch 2020", ParseError::InvalidMonthName),
+        ("2020-Feb-30", ParseError::OutOfRangeDay),
+        ("29 Feb 2023", ParseError::OutOfRangeDay),
+        ("2020-Jan-1", ParseError::InvalidCharDay),
+        ("2020-Jan-01x", ParseError::ExtraCharacters),
+        ("01 Jan 2020x", ParseError::ExtraCharacters),
+        ("01 Jan 20x0", ParseError::InvalidCharYear),
+        ("2020_Jan_01", ParseError::InvalidCharDateSep),
+        ("01-Jan-2020", ParseError::InvalidCharDateSep),
+        ("2020-Jan", ParseError::TooShort),
+    ] {
+        match Date::parse_named_month_str(input) {
+            Ok(t) => panic!("unexpectedly valid: {input:?} -> {t:?}"),
+            Err(e) => assert_eq!(e, error, "{input:?}"),
+        }
+    }
+}
+
+#[test]
+fn date_iso_weeks_in_year() {
+    assert_eq!(Date::iso_weeks_in_year(2020), 53);
+    assert_eq!(Date::iso_weeks_in_year(2021), 52);
+    assert_eq!(Date::iso_weeks_in_year(2026), 53);
+    assert_eq!(Date::iso_weeks_in_year(2004), 53);
+    assert_eq!(Date::iso_weeks_in_year(2000), 52);
+    assert_eq!(Date::iso_weeks_in_year(0), 52);
+    // compare with chrono for a long range of years
+    for year in 1..=9999u16 {
+        let last_week = NaiveDate::from_ymd_opt(year as i32, 12, 28).unwrap().iso_week().week();
+        assert_eq!(Date::iso_weeks_in_year(year), last_week as u8, "{year}");
+    }
+}
+
+#[test]
+fn date_weekday_add_days() {
+    assert_eq!(Date::parse_str("1970-01-01").unwrap().weekday(), 3);
+    assert_eq!(Date::parse_str("2022-06-06").unwrap().weekday(), 0);
+    assert_eq!(Date::parse_str("2022-06-12").unwrap().weekday(), 6);
+    assert_eq!(Date::MIN.weekday(), 5);
+    for ts in (-62_167_219_200..253_402_300_799).step_by(86_400 * 97) {
+        let d = Date::from_timestamp(ts * 1_000, false).unwrap();
+        let chrono_date = NaiveDate::from_ymd_opt(d.year as i32, d.month as u32, d.day as u32).unwrap();
+        assert_eq!(d.weekday() as u32, chrono_date.weekday().num_days_from_monday(), "{d}");
+    }
+
+    let d = Date::parse_str("2020-02-28").unwrap();
+    assert_eq!(d.add_days(1).unwrap().to_string(), "2020-02-29");
+    assert_eq!(d.add_days(2).unwrap().to_string(), "2020-03-01");
+    assert_eq!(d.add_days(0).unwrap(), d);
+    assert_eq!(d.add_days(-59).unwrap().to_string(), "2019-12-31");
+    assert_eq!(Date::MAX.add_days(1).unwrap_err(), ParseError::DateTooLarge);
+    assert_eq!(Date::MIN.add_days(-1).unwrap_err(), ParseError::DateTooSmall);
+    assert_eq!(d.add_days(i64::MAX).unwrap_err(), ParseError::DateTooLarge);
+    assert_eq!(d.add_days(i64::MIN).unwrap_err(), ParseError::DateTooSmall);
+}
+
+#[test]
+fn date_completed_years_until() {
+    let date = |s| Date::parse_str(s).unwrap();
+    let birth = date("2000-03-15");
+    for (as_of, years) in [
+        ("2020-03-14", 19),
+        ("2020-03-15", 20),
+        ("2020-12-31", 20),
+        ("2000-03-15", 0),
+        ("2001-03-14", 0),
+        ("2001-03-15", 1),
+        ("2000-03-14", 0),
+        ("1999-03-16", 0),
+        ("1999-03-15", -1),
+        ("1980-01-01", -20),
+    ] {
+        assert_eq!(birth.completed_years_until(&date(as_of)), years, "{as_of}");
+    }
+
+    // a leap day anniversary is reached on the 28th of February in common years
+    let birth = date("2000-02-29");
+    for (as_of, years) in [
+        ("2001-02-27", 0),
+        ("2001-02-28", 1),
+        ("2001-03-01", 1),
+        ("2004-02-28", 3),
+        ("2004-02-29", 4),
+    ] {
+        assert_eq!(birth.completed_years_until(&date(as_of)), years, "{as_of}");
+    }
+    assert_eq!(date("0000-01-01").completed_years_until(&date("9999-12-31")), 9999);
+}
+
+#[test]
+fn date_start_end_of_week() {
+    let week = |d: &str, week_start| {
+        let d = Date::parse_str(d).unwrap();
+        (
+            d.start_of_week(week_start).unwrap().to_string(),
+            d.end_of_week(week_start).unwrap().to_string(),
+        )
+    };
+    let monday = ("2022-06-06".to_string(), "2022-06-12".to_string());
+    // a Wednesday, the Monday, and the Sunday
+    assert_eq!(week("2022-06-08", WeekStart::Monday), monday);
+    assert_eq!(week("2022-06-06", WeekStart::Monday), monday);
+    assert_eq!(week("2022-06-12", WeekStart::Monday), monday);
+
+    assert_eq!(
+        week("2022-06-08", WeekStart::Sunday),
+        ("2022-06-05".to_string(), "2022-06-11".to_string())
+    );
+    assert_eq!(
+        week("2022-06-12", WeekStart::Sunday),
+        ("2022-06-12".to_string(), "2022-06-18".to_string())
+    );
+    assert_eq!(
+        week("2022-06-11", WeekStart::Sunday),
+        ("2022-06-05".to_string(), "2022-06-11".to_string())
+    );
+    // weeks can span years
+    assert_eq!(
+        week("2021-01-01", WeekStart::Monday),
+        ("2020-12-28".to_string(), "2021-01-03".to_string())
+    );
+    assert_eq!(WeekStart::default(), WeekStart::Monday);
+
+    // 0000-01-01 was a Saturday
+    assert_eq!(
+        Date::MIN.start_of_week(WeekStart::Monday).unwrap_err(),
+        ParseError::DateTooSmall
+    );
+    // 9999-12-31 was a Friday
+    assert_eq!(
+        Date::MAX.end_of_week(WeekStart::Monday).unwrap_err(),
+        ParseError::DateTooLarge
+    );
+}
+
+#[test]
+fn date_time_suffix() {
+    let config = DateConfigBuilder::new().allow_time_suffix(true).build();
+    for input in [
+        "2020-01-01T00:00:00",
+        "2020-01-01 00:00",
+        "2020-01-01t00:00:00.000000",
+        "2020-01-01T00:00:00Z",
+        "2020-01-01T00:00:00+01:00",
+    ] {
+        let d = Date::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(d.to_string(), "2020-01-01", "{input:?}");
+    }
+    for (input, error) in [
+        ("2020-01-01T01:00:00", ParseError::DateNotExact),
+        ("2020-01-01T00:00:00.000001", ParseError::DateNotExact),
+        ("2020-01-01T00:00:00x", ParseError::InvalidCharTzSign),
+        ("2020-01-01T00", ParseError::TooShort),
+        ("2020-01-01x00:00:00", ParseError::ExtraCharacters),
+        ("2020-01-32T00:00:00", ParseError::OutOfRangeDay),
+    ] {
+        match Date::parse_bytes_with_config(input.as_bytes(), &config) {
+            Ok(t) => panic!("unexpectedly valid: {input:?} -> {t:?}"),
+            Err(e) => assert_eq!(e, error, "{input:?}"),
+        }
+    }
+
+    // by default the time is rejected
+    assert_eq!(
+        Date::parse_str("2020-01-01T00:00:00").unwrap_err(),
+        ParseError::ExtraCharacters
+    );
+}
+
+#[test]
+fn date_parse_list() {
+    let dates = Date::parse_list("2020-01-01,2020-02-01,2020-03-01", ',').unwrap();
+    let dates: Vec<String> = dates.iter().map(ToString::to_string).collect();
+    assert_eq!(dates, ["2020-01-01", "2020-02-01", "2020-03-01"]);
+
+    let dates = Date::parse_list("2020-01-01;1577836800", ';').unwrap();
+    let date = Date::parse_str("2020-01-01").unwrap();
+    assert_eq!(dates, [date.clone(), date]);
+
+    assert_eq!(
+        Date::parse_list("2020-01-01,2020-02-30,2020-03-01", ','),
+        Err((1, ParseError::OutOfRangeDay))
+    );
+    assert_eq!(Date::parse_list("2020-01-01,", ','), Err((1, ParseError::TooShort)));
+    assert_eq!(Date::parse_list("", ','), Ok(vec![]));
+}
+
+#[test]
+fn date_field_order() {
+    let dmy = DateConfigBuilder::new().field_order(DateFieldOrder::Dmy).build();
+    let mdy = DateConfigBuilder::new().field_order(DateFieldOrder::Mdy).build();
+    let d = Date::parse_bytes_with_config(b"31/12/2020", &dmy).unwrap();
+    assert_eq!(d.to_string(), "2020-12-31");
+    let d = Date::parse_bytes_with_config(b"12/31/2020", &mdy).unwrap();
+    assert_eq!(d.to_string(), "2020-12-31");
+    let d = Date::parse_bytes_with_config(b"01/02/2020", &dmy).unwrap();
+    assert_eq!(d.to_string(), "2020-02-01");
+    let d = Date::parse_bytes_with_config(b"01/02/2020", &mdy).unwrap();
+    assert_eq!(d.to_string(), "2020-01-02");
+    let d = Date::parse_bytes_with_config(b"01-02-2020", &dmy).unwrap();
+    assert_eq!(d.to_string(), "2020-02-01");
+    let d = Date::parse_bytes_with_config(b"01.02.2020", &dmy).unwrap();
+    assert_eq!(d.to_string(), "2020-02-01");
+    // ISO dates are still supported
+    let d = Date::parse_bytes_with_config(b"2020-01-02", &dmy).unwrap();
+    assert_eq!(d.to_string(), "2020-01-02");
+
+    // a two digit year pivot doesn't apply with the year last
+    let dmy_pivot = DateConfigBuilder::new()
+        .field_order(DateFieldOrder::Dmy)
+        .two_digit_year_pivot(Some(50))
+        .build();
+    let d = Date::parse_bytes_with_config(b"01-02-2020", &dmy_pivot).unwrap();
+    assert_eq!(d.to_string(), "2020-02-01");
+    assert_eq!(
+        Date::parse_bytes_with_config(b"20-01-02", &dmy_pivot).unwrap_err(),
+        ParseError::TooShort
+    );
+
+    assert_eq!(
+        Date::parse_bytes_with_config(b"13/01/2020", &mdy).unwrap_err(),
+        ParseError::OutOfRangeMonth
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"12/31/2020", &dmy).unwrap_err(),
+        ParseError::OutOfRangeMonth
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"29/02/2021", &dmy).unwrap_err(),
+        ParseError::OutOfRangeDay
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"01/02-2020", &dmy).unwrap_err(),
+        ParseError::InvalidCharDateSep
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"x1/02/2020", &mdy).unwrap_err(),
+        ParseError::InvalidCharMonth
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"01/x2/2020", &mdy).unwrap_err(),
+        ParseError::InvalidCharDay
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"01/02/20x0", &dmy).unwrap_err(),
+        ParseError::InvalidCharYear
+    );
+    assert_eq!(
+        Date::parse_bytes_rfc3339_with_config(b"01/02/2020x", &dmy).unwrap_err(),
+        ParseError::ExtraCharacters
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"01/02/202", &dmy).unwrap_err(),
+        ParseError::TooShort
+    );
+    // the default field order doesn't allow year last dates
+    assert_eq!(
+        Date::parse_bytes_with_config(b"31/12/2020", &DateConfig::default()).unwrap_err(),
+        ParseError::InvalidCharYear
+    );
+    assert_eq!(DateConfig::builder().build().field_order, DateFieldOrder::Ymd);
+}
+
+#[test]
+fn date_two_digit_year_default() {
+    assert_eq!(DateConfig::builder().build(), DateConfig::default());
+    assert_eq!(
+        Date::parse_bytes_with_config(b"20-01-01", &DateConfig::default()).unwrap_err(),
+        ParseError::TooShort
+    );
+    assert_eq!(Date::parse_str("20-01-01").unwrap_err(), ParseError::TooShort);
+}
+
 #[test]
 fn date_from_timestamp_extremes() {
     match Date::from_timestamp(i64::MIN, false) {
@@ -215,7 +571,7 @@ fn date_from_timestamp_milliseconds() {
 }
 
 fn try_date_timestamp(ts: i64, check_timestamp: bool) {
-    let chrono_date = NaiveDateTime::from_timestamp_opt(ts, 0).unwrap().date();
+    let chrono_date = ChronoDateTime::from_timestamp(ts, 0).unwrap().naive_utc().date();
     let d = Date::from_timestamp(ts, false).unwrap();
     // println!("{} => {:?}", ts, d);
     assert_eq!(
@@ -276,6 +632,115 @@ fn date_timestamp_exact() {
     }
 }
 
+#[test]
+fn min_max_consts() {
+    assert_eq!(Date::MIN.to_string(), "0000-01-01");
+    assert_eq!(Date::MAX.to_string(), "9999-12-31");
+    assert_eq!(Time::MIN.to_string(), "00:00:00");
+    assert_eq!(Time::MAX.to_string(), "23:59:59.999999");
+    assert_eq!(DateTime::MIN.to_string(), "0000-01-01T00:00:00");
+    assert_eq!(DateTime::MAX.to_string(), "9999-12-31T23:59:59.999999");
+    assert!(Date::MIN < Date::MAX);
+    assert!(Time::MIN < Time::MAX);
+    assert!(DateTime::MIN < DateTime::MAX);
+
+    // timestamps are in milliseconds at these magnitudes
+    let min_ms = DateTime::MIN.timestamp() * 1_000;
+    let max_ms = DateTime::MAX.timestamp() * 1_000;
+    assert_eq!(min_ms, -62_167_219_200_000);
+    assert_eq!(max_ms, 253_402_300_799_000);
+
+    assert_eq!(DateTime::from_timestamp(min_ms, 0).unwrap(), DateTime::MIN);
+    match DateTime::from_timestamp(min_ms - 1, 0) {
+        Ok(dt) => panic!("unexpectedly valid, {dt}"),
+        Err(e) => assert_eq!(e, ParseError::DateTooSmall),
+    }
+    assert_eq!(DateTime::from_timestamp(max_ms, 999_999).unwrap(), DateTime::MAX);
+    match DateTime::from_timestamp(max_ms + 1_000, 0) {
+        Ok(dt) => panic!("unexpectedly valid, {dt}"),
+        Err(e) => assert_eq!(e, ParseError::DateTooLarge),
+    }
+    match DateTime::from_timestamp(max_ms, 1_000_000) {
+        Ok(dt) => panic!("unexpectedly valid, {dt}"),
+        Err(e) => assert_eq!(e, ParseError::DateTooLarge),
+    }
+
+    assert_eq!(Date::from_timestamp(min_ms, true).unwrap(), Date::MIN);
+    match Date::from_timestamp(min_ms - 1, false) {
+        Ok(d) => panic!("unexpectedly valid, {d}"),
+        Err(e) => assert_eq!(e, ParseError::DateTooSmall),
+    }
+    assert_eq!(
+        Date::from_timestamp(Date::MAX.timestamp() * 1_000, true).unwrap(),
+        Date::MAX
+    );
+    assert_eq!(Date::from_timestamp(max_ms, false).unwrap(), Date::MAX);
+    match Date::from_timestamp(max_ms + 1_000, false) {
+        Ok(d) => panic!("unexpectedly valid, {d}"),
+        Err(e) => assert_eq!(e, ParseError::DateTooLarge),
+    }
+
+    assert_eq!(
+        DateTime::from_timestamp(-20_000_000_000, 0).unwrap().to_string(),
+        "1336-03-23T12:26:40"
+    );
+}
+
+#[test]
+fn date_checked_timestamp() {
+    for year in 0..=9999 {
+        for month in 1..=12 {
+            for day in [1, 15, 28] {
+                let d = Date { year, month, day };
+                let ts = d.checked_timestamp();
+                assert_eq!(ts, Some(d.timestamp()), "{d}");
+                // from_timestamp interprets large values as milliseconds
+                let ts = ts.unwrap();
+                let ts = if ts.abs() > 20_000_000_000 { ts * 1_000 } else { ts };
+                assert_eq!(Date::from_timestamp(ts, true).unwrap(), d);
+            }
+        }
+    }
+    let max = Date {
+        year: 9999,
+        month: 12,
+        day: 31,
+    };
+    assert_eq!(max.checked_timestamp(), Some(253_402_214_400));
+    let max = Date {
+        year: u16::MAX,
+        month: 12,
+        day: 31,
+    };
+    assert_eq!(max.checked_timestamp(), Some(max.timestamp()));
+}
+
+#[test]
+fn date_checked_timestamp_ms() {
+    for year in 0..=9999 {
+        for month in 1..=12 {
+            for day in [1, 28] {
+                let d = Date { year, month, day };
+                let ts = d.checked_timestamp_ms();
+                assert_eq!(ts, Some(d.timestamp() * 1000), "{d}");
+                assert_eq!(ts, Some(d.timestamp_ms()), "{d}");
+            }
+        }
+    }
+    let min = Date {
+        year: 0,
+        month: 1,
+        day: 1,
+    };
+    assert_eq!(min.checked_timestamp_ms(), Some(-62_167_219_200_000));
+    let max = Date {
+        year: u16::MAX,
+        month: 12,
+        day: 31,
+    };
+    assert_eq!(max.checked_timestamp_ms(), Some(max.timestamp_ms()));
+}
+
 macro_rules! date_from_timestamp {
     ($($year:literal, $month:literal, $day:literal;)*) => {
         $(
@@ -283,7 +748,7 @@ macro_rules! date_from_timestamp {
             #[test]
             fn [< date_from_timestamp_ $year _ $month _ $day >]() {
                 let chrono_date = NaiveDate::from_ymd_opt($year, $month, $day).unwrap();
-                let ts = chrono_date.and_hms_opt(0, 0, 0).unwrap().timestamp();
+                let ts = chrono_date.and_hms_opt(0, 0, 0).unwrap().and_utc().timestamp();
                 let d = Date::from_timestamp(ts, false).unwrap();
                 assert_eq!(
                     d,
@@ -323,6 +788,7 @@ date_from_timestamp! {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn date_today() {
     let today = Date::today(0).unwrap();
     let chrono_now = ChronoUtc::now();
@@ -337,6 +803,7 @@ fn date_today() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn date_today_offset() {
     for offset in (-86399..86399).step_by(1000) {
         let today = Date::today(offset).unwrap();
@@ -405,7 +872,7 @@ fn time_from_timestamp_error() {
 }
 
 fn try_datetime_timestamp(chrono_dt: NaiveDateTime) {
-    let ts = chrono_dt.timestamp();
+    let ts = chrono_dt.and_utc().timestamp();
     let dt = DateTime::from_timestamp(ts, chrono_dt.nanosecond() / 1_000).unwrap();
     // println!("{} ({}) => {}", ts, chrono_dt, dt);
     assert_eq!(
@@ -455,8 +922,47 @@ datetime_from_timestamp! {
 #[test]
 fn datetime_from_timestamp_range() {
     for ts in (0..157_766_400).step_by(757) {
-        try_datetime_timestamp(NaiveDateTime::from_timestamp_opt(ts, 0).unwrap());
-        try_datetime_timestamp(NaiveDateTime::from_timestamp_opt(-ts, 0).unwrap());
+        try_datetime_timestamp(ChronoDateTime::from_timestamp(ts, 0).unwrap().naive_utc());
+        try_datetime_timestamp(ChronoDateTime::from_timestamp(-ts, 0).unwrap().naive_utc());
+    }
+}
+
+#[test]
+fn datetime_timestamp_round_trip_range() {
+    for ts in (-19_999_999_999..=19_999_999_999).step_by(7_777_777) {
+        for ts in [ts - 1, ts, ts + 1] {
+            for microsecond in [0, 1, 499_999, 500_000, 999_999] {
+                let dt = DateTime::from_timestamp(ts, microsecond).unwrap();
+                assert_eq!(dt.timestamp(), ts, "{ts} {microsecond}");
+                assert_eq!(dt.time.microsecond, microsecond, "{ts} {microsecond}");
+                let round_trip = DateTime::from_timestamp(dt.timestamp(), dt.time.microsecond).unwrap();
+                assert_eq!(round_trip, dt, "{ts} {microsecond}");
+            }
+        }
+    }
+}
+
+#[test]
+fn datetime_timestamp_round_trip_watershed() {
+    for s in [
+        "1336-03-23T12:26:40.000001",
+        "1969-12-31T23:59:59.999999",
+        "1970-01-01T00:00:00.000001",
+        "2603-10-11T11:33:20.999999",
+    ] {
+        let dt = DateTime::parse_str(s).unwrap();
+        let round_trip = DateTime::from_timestamp(dt.timestamp(), dt.time.microsecond).unwrap();
+        assert_eq!(round_trip.to_string(), s);
+    }
+    // outside the range of second timestamps, the timestamp must be given in milliseconds
+    for s in [
+        "0001-01-01T00:00:00.000001",
+        "1336-03-23T12:26:39.999999",
+        "9999-12-31T23:59:59.999999",
+    ] {
+        let dt = DateTime::parse_str(s).unwrap();
+        let round_trip = DateTime::from_timestamp(dt.timestamp() * 1000, dt.time.microsecond).unwrap();
+        assert_eq!(round_trip.to_string(), s);
     }
 }
 
@@ -481,6 +987,27 @@ fn datetime_from_timestamp_specific() {
     }
 }
 
+#[test]
+fn datetime_milliseconds_fraction() {
+    // the fraction of a millisecond timestamp gives microseconds
+    let dt = DateTime::parse_str("1711445175471.865").unwrap();
+    assert_eq!(dt.to_string(), "2024-03-26T09:26:15.471865");
+    let dt = DateTime::parse_str("1711445175471.5").unwrap();
+    assert_eq!(dt.to_string(), "2024-03-26T09:26:15.471500");
+    let dt = DateTime::parse_str("-1711445175471.865").unwrap();
+    assert_eq!(dt.to_string(), "1915-10-08T14:33:44.528135");
+
+    assert_eq!(
+        DateTime::parse_str("1711445175471.8654").unwrap_err(),
+        ParseError::MillisecondFractionTooLong
+    );
+    let config = TimeConfigBuilder::new()
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Truncate)
+        .build();
+    let dt = DateTime::parse_bytes_with_config(b"1711445175471.8654", &config).unwrap();
+    assert_eq!(dt.to_string(), "2024-03-26T09:26:15.471865");
+}
+
 #[test]
 fn datetime_watershed() {
     let dt = DateTime::from_timestamp(20_000_000_000, 0).unwrap();
@@ -494,6 +1021,7 @@ fn datetime_watershed() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn datetime_now() {
     let speedate_now = DateTime::now(0).unwrap();
     let chrono_now = ChronoUtc::now();
@@ -502,6 +1030,7 @@ fn datetime_now() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn datetime_now_offset() {
     let speedate_now = DateTime::now(3600).unwrap();
     let chrono_now = ChronoUtc::now();
@@ -557,6 +1086,191 @@ fn datetime_in_timezone() {
     assert_eq!(error, ParseError::OutOfRangeTz);
 }
 
+#[test]
+fn datetime_add_sub_duration() {
+    let dt = DateTime::parse_str("2020-02-28T23:59:59.999999+01:00").unwrap();
+
+    let d = Duration::parse_str("PT0.000001S").unwrap();
+    assert_eq!(dt.add_duration(&d).unwrap().to_string(), "2020-02-29T00:00:00+01:00");
+    assert_eq!(
+        dt.sub_duration(&d).unwrap().to_string(),
+        "2020-02-28T23:59:59.999998+01:00"
+    );
+
+    let d = Duration::parse_str("-P1DT0.5S").unwrap();
+    assert_eq!(
+        dt.add_duration(&d).unwrap().to_string(),
+        "2020-02-27T23:59:59.499999+01:00"
+    );
+    assert_eq!(
+        dt.sub_duration(&d).unwrap().to_string(),
+        "2020-03-01T00:00:00.499999+01:00"
+    );
+
+    let d = Duration::parse_str("P365D").unwrap();
+    assert_eq!(
+        dt.add_duration(&d).unwrap().to_string(),
+        "2021-02-27T23:59:59.999999+01:00"
+    );
+
+    let naive = DateTime::parse_str("1970-01-01T00:00:00").unwrap();
+    let d = Duration::parse_str("PT1S").unwrap();
+    assert_eq!(naive.sub_duration(&d).unwrap().to_string(), "1969-12-31T23:59:59");
+}
+
+#[test]
+fn datetime_round_to() {
+    let round = |dt: &str, step: &str| {
+        let dt = DateTime::parse_str(dt).unwrap();
+        dt.round_to(&Duration::parse_str(step).unwrap()).unwrap().to_string()
+    };
+    // exactly half way rounds up
+    assert_eq!(round("2022-01-01T12:07:30", "PT15M"), "2022-01-01T12:15:00");
+    assert_eq!(round("2022-01-01T12:07:29.999999", "PT15M"), "2022-01-01T12:00:00");
+    assert_eq!(round("2022-01-01T12:07:30Z", "PT1H"), "2022-01-01T12:00:00Z");
+    assert_eq!(round("2022-01-01T12:30:00+02:00", "PT1H"), "2022-01-01T13:00:00+02:00");
+    assert_eq!(round("2022-01-01T23:59:59.5", "PT1S"), "2022-01-02T00:00:00");
+    assert_eq!(
+        round("2022-01-01T12:13:14.123456", "PT0.001S"),
+        "2022-01-01T12:13:14.123000"
+    );
+    // steps larger than a day
+    assert_eq!(round("2022-01-01T12:00:00", "P1D"), "2022-01-02T00:00:00");
+    assert_eq!(round("2022-01-01T11:59:59", "P1D"), "2022-01-01T00:00:00");
+    assert_eq!(round("2022-01-03T00:00:00", "P2D"), "2022-01-04T00:00:00");
+    // dates before 1970 are rounded the same way
+    assert_eq!(round("1969-12-31T23:52:30", "PT15M"), "1970-01-01T00:00:00");
+    assert_eq!(round("1969-12-31T23:52:29", "PT15M"), "1969-12-31T23:45:00");
+    // the sign of step is ignored
+    assert_eq!(round("2022-01-01T12:07:30", "-PT15M"), "2022-01-01T12:15:00");
+
+    let dt = DateTime::parse_str("2022-01-01T12:07:30").unwrap();
+    assert_eq!(dt.round_to(&Duration::ZERO).unwrap_err(), ParseError::DurationStepZero);
+    assert_eq!(
+        DateTime::MAX
+            .round_to(&Duration::parse_str("PT1S").unwrap())
+            .unwrap_err(),
+        ParseError::DateTooLarge
+    );
+    assert_eq!(
+        DateTime::MIN.round_to(&Duration::MAX).unwrap().to_string(),
+        "1970-01-01T00:00:00"
+    );
+}
+
+#[test]
+fn datetime_floor_ceil_to() {
+    let floor = |dt: &str, step: &str| {
+        let dt = DateTime::parse_str(dt).unwrap();
+        dt.floor_to(&Duration::parse_str(step).unwrap()).unwrap().to_string()
+    };
+    let ceil = |dt: &str, step: &str| {
+        let dt = DateTime::parse_str(dt).unwrap();
+        dt.ceil_to(&Duration::parse_str(step).unwrap()).unwrap().to_string()
+    };
+    assert_eq!(floor("2022-01-01T12:07:30", "PT5M"), "2022-01-01T12:05:00");
+    assert_eq!(
+        floor("2022-01-01T12:59:59.999999+01:00", "PT1H"),
+        "2022-01-01T12:00:00+01:00"
+    );
+    assert_eq!(floor("2022-01-01T12:05:00", "PT5M"), "2022-01-01T12:05:00");
+    assert_eq!(floor("2022-01-01T12:07:30", "P1D"), "2022-01-01T00:00:00");
+    assert_eq!(floor("1969-12-31T23:59:59", "PT1H"), "1969-12-31T23:00:00");
+    assert_eq!(ceil("2022-01-01T12:07:30", "PT1H"), "2022-01-01T13:00:00");
+    assert_eq!(ceil("2022-01-01T12:00:00.000001Z", "PT1H"), "2022-01-01T13:00:00Z");
+    assert_eq!(ceil("2022-01-01T12:00:00", "PT1H"), "2022-01-01T12:00:00");
+    assert_eq!(ceil("2022-01-01T12:07:30", "P1D"), "2022-01-02T00:00:00");
+    assert_eq!(ceil("1969-12-31T23:00:01", "PT1H"), "1970-01-01T00:00:00");
+
+    let dt = DateTime::parse_str("2022-01-01T12:07:30").unwrap();
+    assert_eq!(dt.floor_to(&Duration::ZERO).unwrap_err(), ParseError::DurationStepZero);
+    assert_eq!(dt.ceil_to(&Duration::ZERO).unwrap_err(), ParseError::DurationStepZero);
+    assert_eq!(
+        DateTime::MAX
+            .ceil_to(&Duration::parse_str("PT1S").unwrap())
+            .unwrap_err(),
+        ParseError::DateTooLarge
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn datetime_serde_timestamp() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Event {
+        #[serde(with = "speedate::serde::timestamp")]
+        at: DateTime,
+    }
+
+    let event = Event {
+        at: DateTime::parse_str("2022-06-07T16:28:40").unwrap(),
+    };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"at":1654619320}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+    let event = Event {
+        at: DateTime::parse_str("2022-06-07T16:28:40.123456").unwrap(),
+    };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"at":1654619320.123456}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+    let event = Event {
+        at: DateTime::parse_str("1969-12-31T23:59:59.5").unwrap(),
+    };
+    let json = serde_json::to_string(&event).unwrap();
+    assert_eq!(json, r#"{"at":-0.5}"#);
+    assert_eq!(serde_json::from_str::<Event>(&json).unwrap(), event);
+
+    // values are always seconds, even above the millisecond watershed
+    for dt in [DateTime::MIN, DateTime::parse_str("9999-12-31T23:59:59").unwrap()] {
+        let json = serde_json::to_string(&Event { at: dt.clone() }).unwrap();
+        assert_eq!(serde_json::from_str::<Event>(&json).unwrap().at, dt);
+    }
+
+    // the timezone offset is not preserved
+    let event = Event {
+        at: DateTime::parse_str("2022-06-07T16:28:40+01:00").unwrap(),
+    };
+    assert_eq!(serde_json::to_string(&event).unwrap(), r#"{"at":1654619320}"#);
+
+    let error = serde_json::from_str::<Event>(r#"{"at":253402300800}"#).unwrap_err();
+    assert_eq!(error.to_string(), "date_too_large at line 1 column 18");
+    let error = serde_json::from_str::<Event>(r#"{"at":"2022-06-07"}"#).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid type: string \"2022-06-07\", expected a unix timestamp in seconds at line 1 column 18"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn datetime_ago_hence() {
+    let now = DateTime::now(0).unwrap();
+    let day = Duration::parse_str("P1D").unwrap();
+    let yesterday = now.ago(&day).unwrap();
+    assert_eq!(yesterday.timestamp(), now.timestamp() - 86_400);
+    assert_eq!(yesterday.time.microsecond, now.time.microsecond);
+    assert!(yesterday < now);
+    assert_eq!(yesterday.hence(&day).unwrap(), now);
+
+    let min = DateTime::parse_str("0000-01-01T00:00:00").unwrap();
+    assert_eq!(min.ago(&Duration::new(true, 0, 0, 0).unwrap()).unwrap(), min);
+    assert_eq!(
+        min.ago(&Duration::parse_str("PT0.000001S").unwrap()).unwrap_err(),
+        ParseError::DateTooSmall
+    );
+
+    let max = DateTime::parse_str("9999-12-31T23:59:59.999999").unwrap();
+    assert_eq!(
+        max.hence(&Duration::parse_str("PT0.000001S").unwrap()).unwrap_err(),
+        ParseError::DateTooLarge
+    );
+}
+
 #[test]
 fn time() {
     let t = Time::parse_str("12:13:14.123456").unwrap();
@@ -577,6 +1291,13 @@ fn time() {
     );
 }
 
+#[test]
+fn time_default() {
+    let t = Time::default();
+    assert_eq!(t.to_string(), "00:00:00");
+    assert_eq!(t.tz_offset, None);
+}
+
 #[test]
 fn time_comparison() {
     let t1 = Time::parse_str("12:13:14").unwrap();
@@ -628,6 +1349,17 @@ fn time_total_seconds() {
     assert_eq!(t.total_seconds(), 12 * 3600 + 13 * 60 + 14);
 }
 
+#[test]
+fn time_seconds_of_day_f64() {
+    let t = Time::parse_str("12:13:14.5").unwrap();
+    assert_eq!(t.seconds_of_day_f64(), 43_994.5);
+    let t = Time::parse_str("12:13:14.5+02:00").unwrap();
+    assert_eq!(t.seconds_of_day_f64(), 43_994.5);
+    assert_eq!(Time::parse_str("00:00:00").unwrap().seconds_of_day_f64(), 0.0);
+    let t = Time::parse_str("23:59:59.999999").unwrap();
+    assert_eq!(t.seconds_of_day_f64(), 86_399.999_999);
+}
+
 #[test]
 fn time_with_tz_offset() {
     let t_z = Time::parse_str("12:13:14.567+00:00").unwrap();
@@ -650,6 +1382,30 @@ fn time_with_tz_offset() {
     assert_eq!(error, ParseError::OutOfRangeTz);
 }
 
+#[test]
+fn time_to_string_z_as_offset() {
+    let t = Time::parse_str("12:13:14.5+00:00").unwrap();
+    assert_eq!(t.to_string(), "12:13:14.500000Z");
+    assert_eq!(t.to_string_z_as_offset(), "12:13:14.500000+00:00");
+
+    let t = Time::parse_str("12:13:14+01:00").unwrap();
+    assert_eq!(t.to_string_z_as_offset(), t.to_string());
+    let t = Time::parse_str("12:13:14").unwrap();
+    assert_eq!(t.to_string_z_as_offset(), "12:13:14");
+}
+
+#[test]
+fn datetime_to_string_z_as_offset() {
+    let dt = DateTime::parse_str("2022-01-01T12:13:14z").unwrap();
+    assert_eq!(dt.to_string(), "2022-01-01T12:13:14Z");
+    assert_eq!(dt.to_string_z_as_offset(), "2022-01-01T12:13:14+00:00");
+
+    let dt = DateTime::parse_str("2022-01-01T12:13:14-08:30").unwrap();
+    assert_eq!(dt.to_string_z_as_offset(), dt.to_string());
+    let dt = DateTime::parse_str("2022-01-01T12:13:14").unwrap();
+    assert_eq!(dt.to_string_z_as_offset(), "2022-01-01T12:13:14");
+}
+
 #[test]
 fn time_in_timezone() {
     let t_z = Time::parse_str("15:00:00.567Z").unwrap();
@@ -682,6 +1438,26 @@ param_tests! {
     time_fraction_small: ok => "12:13:14.123", "12:13:14.123000";
     time_no_sec: ok => "12:13", "12:13:00";
     time_tz: ok => "12:13:14z", "12:13:14Z";
+    time_duplicate_tz_offset_z: err => "12:13:14+02:00Z", DuplicateTimezone;
+    time_duplicate_tz_z_z: err => "12:13:14ZZ", DuplicateTimezone;
+    time_duplicate_tz_z_lower: err => "12:13:14Zz", DuplicateTimezone;
+    time_duplicate_tz_offsets: err => "12:13:14+02:00-01:00", DuplicateTimezone;
+    time_duplicate_tz_minus: err => "12:13:14Z−01:00", DuplicateTimezone;
+    time_extra_after_tz: err => "12:13:14+02:00x", ExtraCharacters;
+    time_space_before_hour_sep: err => "12 :13:14", UnexpectedWhitespace;
+    time_space_in_hour: err => "1 2:13:14", UnexpectedWhitespace;
+    time_space_after_hour_sep: err => "12: 13:14", UnexpectedWhitespace;
+    time_space_in_minute: err => "12:1 3:14", UnexpectedWhitespace;
+    time_space_after_minute_sep: err => "12:13: 14", UnexpectedWhitespace;
+    time_space_in_second: err => "12:13:1 4", UnexpectedWhitespace;
+    time_tab_in_minute: err => "12:1\t3:14", UnexpectedWhitespace;
+    time_space_leading: err => " 12:13:14", UnexpectedWhitespace;
+    time_space_after_fraction_sep: err => "12:13:14. 5", SecondFractionMissing;
+    time_space_after_minute: err => "12:13 :14", InvalidCharTzSign;
+    time_space_after_bad_hour: err => "123 :13:14", InvalidCharTimeSep;
+    time_space_after_bad_minute: err => "12:x :14", InvalidCharMinute;
+    time_space_after_bad_second: err => "12:13:x 4", InvalidCharSecond;
+    time_x_in_minute: err => "12:1x:14", InvalidCharMinute;
     time: err => "xxx", TooShort;
     time: err => "xx:12", InvalidCharHour;
     time_sep_hour: err => "12x12", InvalidCharTimeSep;
@@ -693,6 +1469,9 @@ param_tests! {
     time: err => "24:00:00", OutOfRangeHour;
     time: err => "23:60:00", OutOfRangeMinute;
     time: err => "23:59:60", OutOfRangeSecond;
+    time_hour_range_before_second: err => "25:00:1x", OutOfRangeHour;
+    time_minute_range_before_second: err => "12:60:1x", OutOfRangeMinute;
+    time_second_range_before_fraction: err => "12:13:60.x", OutOfRangeSecond;
     time_extra_x: err => "23:59:59xxx", InvalidCharTzSign;
     time_extra_space: err => "23:59:59 ", InvalidCharTzSign;
 }
@@ -724,6 +1503,13 @@ fn datetime_naive() {
     );
 }
 
+#[test]
+fn datetime_default() {
+    let dt = DateTime::default();
+    assert_eq!(dt.to_string(), "1970-01-01T00:00:00");
+    assert_eq!(dt.timestamp(), 0);
+}
+
 #[test]
 fn datetime_tz_z() {
     let dt = DateTime::parse_str("2020-01-01 12:13:14z").unwrap();
@@ -748,14 +1534,152 @@ fn datetime_tz_z() {
 }
 
 #[test]
-fn datetime_bytes() {
-    let dt = DateTime::parse_bytes(b"2020-01-01 12:13:14z").unwrap();
-    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
-}
+fn datetime_no_seconds() {
+    for (input, tz_offset, expected) in [
+        ("2020-01-01T12:13Z", Some(0), "2020-01-01T12:13:00Z"),
+        ("2020-01-01T12:13+02:00", Some(7200), "2020-01-01T12:13:00+02:00"),
+        ("2020-01-01T12:13-0130", Some(-5400), "2020-01-01T12:13:00-01:30"),
+        ("2020-01-01T12:13", None, "2020-01-01T12:13:00"),
+        ("2020-01-01 12:13", None, "2020-01-01T12:13:00"),
+    ] {
+        let dt = DateTime::parse_str(input).unwrap();
+        assert_eq!(
+            dt.time,
+            Time {
+                hour: 12,
+                minute: 13,
+                second: 0,
+                microsecond: 0,
+                tz_offset,
+            },
+            "{input:?}"
+        );
+        assert_eq!(dt.to_string(), expected, "{input:?}");
+    }
 
-#[test]
-fn datetime_tz_2hours() {
-    let dt = DateTime::parse_str("2020-01-01T12:13:14+02:00").unwrap();
+    assert_eq!(
+        DateTime::parse_str("2020-01-01T12:13.5Z").unwrap_err(),
+        ParseError::InvalidCharTzSign
+    );
+    assert_eq!(
+        DateTime::parse_str("2020-01-01T12:1Z").unwrap_err(),
+        ParseError::InvalidCharMinute
+    );
+}
+
+#[test]
+fn datetime_misplaced_timezone() {
+    for input in ["2020-01-01Z12:13:14", "2020-01-01z12:13:14", "2020-01-01Z12:13:14Z"] {
+        assert_eq!(
+            DateTime::parse_str(input).unwrap_err(),
+            ParseError::MisplacedTimezone,
+            "{input:?}"
+        );
+    }
+    // a date followed by just `Z` isn't a misplaced separator
+    assert_eq!(
+        DateTime::parse_str("2020-01-01Z").unwrap_err(),
+        ParseError::InvalidCharDateTimeSep
+    );
+    assert_eq!(
+        DateTime::parse_str("2020-01-01X12:13:14").unwrap_err(),
+        ParseError::InvalidCharDateTimeSep
+    );
+}
+
+#[test]
+fn datetime_from_date() {
+    let d = Date::parse_str("2022-06-07").unwrap();
+    let dt = DateTime::from(d.clone());
+    assert_eq!(dt.to_string(), "2022-06-07T00:00:00");
+    assert_eq!(dt.time, Time::default());
+    assert_eq!(dt, d);
+    assert_eq!(Date::from(dt), d);
+
+    // converting to a date drops the time and timezone
+    let dt = DateTime::parse_str("2022-06-07T12:13:14.123+02:00").unwrap();
+    let d2: Date = dt.into();
+    assert_eq!(d2, d);
+    let dt2: DateTime = d2.into();
+    assert_eq!(dt2.to_string(), "2022-06-07T00:00:00");
+}
+
+#[test]
+fn try_from_timestamp() {
+    assert_eq!(Date::try_from(1_577_836_800_i64).unwrap().to_string(), "2020-01-01");
+    assert_eq!(Date::try_from(1_577_836_800_000_i64).unwrap().to_string(), "2020-01-01");
+    assert_eq!(Date::try_from(1_577_836_801_i64), Err(ParseError::DateNotExact));
+    assert_eq!(Date::try_from(i64::MAX), Err(ParseError::DateTooLarge));
+
+    let dt = DateTime::try_from(1_577_880_794_i64).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14");
+    let dt: DateTime = 1_577_880_794_123_i64.try_into().unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14.123000");
+    assert_eq!(DateTime::try_from(i64::MIN), Err(ParseError::DateTooSmall));
+}
+
+#[test]
+fn datetime_zulu_separator_matrix() {
+    let config = TimeConfigBuilder::new().build();
+    let times = [
+        ("12:13", "12:13:00"),
+        ("12:13:14", "12:13:14"),
+        ("12:13:14.5", "12:13:14.500000"),
+        ("12:13:14,5", "12:13:14.500000"),
+        ("12:13:14.123456", "12:13:14.123456"),
+    ];
+    for sep in ['T', 't', ' ', '_'] {
+        for (time, expected_time) in times {
+            for zulu in ['Z', 'z'] {
+                let input = format!("2020-01-01{sep}{time}{zulu}");
+                let expected = format!("2020-01-01T{expected_time}Z");
+
+                let dt = DateTime::parse_str(&input).unwrap_or_else(|e| panic!("{input:?}: {e}"));
+                assert_eq!(dt.to_string(), expected, "{input:?}");
+                assert_eq!(dt.time.tz_offset, Some(0), "{input:?}");
+                let dt = DateTime::parse_str_rfc3339(&input).unwrap_or_else(|e| panic!("{input:?}: {e}"));
+                assert_eq!(dt.to_string(), expected, "{input:?}");
+                let (dt, source) = DateTime::parse_bytes_with_meta(input.as_bytes(), &config).unwrap();
+                assert_eq!((dt.to_string(), source), (expected, TzSource::Zulu), "{input:?}");
+
+                let t = Time::parse_str(&format!("{time}{zulu}")).unwrap();
+                assert_eq!(t.to_string(), format!("{expected_time}Z"), "{time}{zulu}");
+
+                // `Z` may only appear once, and not with an offset
+                for bad in [format!("{input}{zulu}"), format!("{input}+00:00")] {
+                    assert_eq!(DateTime::parse_str(&bad), Err(ParseError::DuplicateTimezone), "{bad:?}");
+                }
+                assert_eq!(
+                    DateTime::parse_str(&format!("{input}x")),
+                    Err(ParseError::ExtraCharacters),
+                    "{input:?}"
+                );
+            }
+        }
+        for zulu in ['Z', 'z'] {
+            // `Z` in place of the separator is misplaced rather than an unknown separator
+            let input = format!("2020-01-01{zulu}12:13:14{zulu}");
+            assert_eq!(
+                DateTime::parse_str(&input),
+                Err(ParseError::MisplacedTimezone),
+                "{input:?}"
+            );
+            // a date followed by only `Z` has no time
+            let input = format!("2020-01-01{sep}{zulu}");
+            assert!(DateTime::parse_str(&input).is_err(), "{input:?}");
+        }
+    }
+}
+
+#[test]
+fn datetime_bytes() {
+    let dt = DateTime::parse_bytes(b"2020-01-01 12:13:14z").unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
+}
+
+#[test]
+fn datetime_tz_2hours() {
+    let dt = DateTime::parse_str("2020-01-01T12:13:14+02:00").unwrap();
     assert_eq!(
         dt,
         DateTime {
@@ -808,6 +1732,31 @@ fn datetime_timestamp() {
     assert_eq!(d_naive.timestamp(), 86400);
 }
 
+#[test]
+fn datetime_timestamp_f64() {
+    for (ts, micro) in [
+        (0, 0),
+        (1_654_619_320, 123_456),
+        (1_000_000_000, 999_999),
+        (-1_654_646_404, 500_000),
+        (9_999_999_999, 999_999),
+        (-11_676_096_000, 1),
+    ] {
+        let dt = DateTime::from_timestamp(ts, micro).unwrap();
+        let expected = ts as f64 + micro as f64 / 1_000_000.0;
+        assert!((dt.timestamp_f64() - expected).abs() < 1e-5, "{dt}");
+        assert!((dt.timestamp_millis_f64() - expected * 1_000.0).abs() < 1e-2, "{dt}");
+        // timestamp in milliseconds round-trips to millisecond precision
+        let dt_ms = DateTime::from_timestamp(dt.timestamp_millis_f64().floor() as i64, 0).unwrap();
+        assert_eq!(dt_ms.timestamp(), dt.timestamp());
+        assert_eq!(dt_ms.time.microsecond, micro / 1000 * 1000);
+    }
+
+    let dt = DateTime::parse_str("2022-01-01T12:13:14.5+01:00").unwrap();
+    assert_eq!(dt.timestamp_f64(), dt.timestamp() as f64 + 0.5);
+    assert_eq!(dt.timestamp_millis_f64(), dt.timestamp() as f64 * 1_000.0 + 500.0);
+}
+
 #[test]
 fn datetime_timestamp_tz() {
     let t_naive = DateTime::parse_str("1970-01-02T00:00").unwrap();
@@ -875,6 +1824,107 @@ fn datetime_comparison_timezone() {
     assert!(dt7 > dt8);
 }
 
+#[test]
+fn datetime_date_comparison() {
+    let d = Date::parse_str("2020-01-01").unwrap();
+
+    let dt_midnight = DateTime::parse_str("2020-01-01T00:00:00").unwrap();
+    assert!(d == dt_midnight);
+    assert!(dt_midnight == d);
+    assert!(d <= dt_midnight);
+    assert!(d >= dt_midnight);
+
+    let dt_noon = DateTime::parse_str("2020-01-01T12:00:00").unwrap();
+    assert!(d != dt_noon);
+    assert!(d < dt_noon);
+    assert!(dt_noon > d);
+
+    let dt_micro = DateTime::parse_str("2020-01-01T00:00:00.000001").unwrap();
+    assert!(d != dt_micro);
+    assert!(d < dt_micro);
+
+    let dt_before = DateTime::parse_str("2019-12-31T23:59:59.999999").unwrap();
+    assert!(d > dt_before);
+    assert!(dt_before < d);
+
+    // the date is taken as midnight in the datetime's timezone
+    let dt_tz = DateTime::parse_str("2020-01-01T00:00:00+05:00").unwrap();
+    assert!(d == dt_tz);
+    assert!(d <= dt_tz);
+    assert!(d >= dt_tz);
+    let dt_tz = DateTime::parse_str("2020-01-01T01:00:00+05:00").unwrap();
+    assert!(d < dt_tz);
+}
+
+#[test]
+fn datetime_parse_many() {
+    let inputs = [
+        "2020-01-01T12:13:14Z",
+        "2020-01-01T25:00:00",
+        "1654646400",
+        "",
+        "2020-02-30",
+        "2020-01-01 00:00:00+01:00",
+    ];
+    let results = DateTime::parse_many(inputs);
+    assert_eq!(results.len(), inputs.len());
+    assert_eq!(results[0].as_ref().unwrap().to_string(), "2020-01-01T12:13:14Z");
+    assert_eq!(results[1], Err((1, ParseError::OutOfRangeHour)));
+    assert_eq!(results[2].as_ref().unwrap().to_string(), "2022-06-08T00:00:00");
+    assert_eq!(results[3], Err((3, ParseError::TooShort)));
+    assert_eq!(results[4], Err((4, ParseError::OutOfRangeDay)));
+    assert_eq!(results[5].as_ref().unwrap().to_string(), "2020-01-01T00:00:00+01:00");
+
+    assert!(DateTime::parse_many([]).is_empty());
+}
+
+#[test]
+fn datetime_fixed_utc_fast_path() {
+    // lowercase `t` and `z` are equivalent but skip the fast path, so compare against them
+    let check = |s: &str| {
+        let slow = s.replace('T', "t").replace('Z', "z");
+        assert_eq!(DateTime::parse_str(s), DateTime::parse_str(&slow), "{s:?}");
+    };
+    let bases = [
+        "2000-01-01T00:02:03Z",
+        "2022-02-28T23:59:59Z",
+        "2024-02-29T12:00:00Z",
+        "0000-12-31T01:01:01Z",
+        "9999-12-31T23:59:59Z",
+    ];
+    for base in bases {
+        check(base);
+        for i in 0..base.len() {
+            for c in [
+                '0', '1', '2', '3', '5', '6', '9', '/', ':', '-', 'T', 'Z', ' ', 'a', '\x7f', '\u{e9}',
+            ] {
+                let mut s = base.to_string();
+                s.replace_range(i..i + 1, c.encode_utf8(&mut [0; 4]));
+                check(&s);
+            }
+        }
+    }
+    for s in [
+        "2023-02-29T00:00:00Z",
+        "2022-13-01T00:00:00Z",
+        "2022-00-01T00:00:00Z",
+        "2022-01-32T00:00:00Z",
+        "2022-01-01T24:00:00Z",
+        "2022-01-01T00:60:00Z",
+        "2022-01-01T00:00:60Z",
+        "2022-01-01T00:00:00+",
+    ] {
+        check(s);
+    }
+    let dt = DateTime::parse_str("2022-06-07T08:09:10Z").unwrap();
+    assert_eq!(dt.to_string(), "2022-06-07T08:09:10Z");
+    assert_eq!(dt.time.tz_offset, Some(0));
+    match DateTime::parse_str("2022-02-30T00:00:00Z") {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::OutOfRangeDay),
+    }
+}
+
 param_tests! {
     DateTime,
     dt_longest: ok => "2020-01-01T12:13:14.123456−02:15", "2020-01-01T12:13:14.123456-02:15";
@@ -914,6 +1964,11 @@ param_tests! {
     dt_extra_space_tz1: err => "2020-01-01T12:00:00+00:00 ", ExtraCharacters;
     dt_extra_space_tz2: err => "2020-01-01T12:00:00+0000 ", ExtraCharacters;
     dt_extra_xxx: err => "2020-01-01T12:00:00Zxxx", ExtraCharacters;
+    dt_duplicate_tz_offset_z: err => "2020-01-01T12:00:00+02:00Z", DuplicateTimezone;
+    dt_space_before_hour_sep: err => "2020-01-01T12 :13:14", UnexpectedWhitespace;
+    dt_space_in_second: err => "2020-01-01T12:13:1 4Z", UnexpectedWhitespace;
+    dt_space_after_date_sep: err => "2020-01-01T 12:13:14", UnexpectedWhitespace;
+    dt_duplicate_tz_z_offset: err => "2020-01-01T12:00:00Z+02:00", DuplicateTimezone;
     tz_pos_2359: ok => "2020-01-01T12:00:00+23:59", "2020-01-01T12:00:00+23:59";
     tz_neg_2359: ok => "2020-01-01T12:00:00-23:59", "2020-01-01T12:00:00-23:59";
     tz_60mins: err => "2020-01-01T12:00:00+00:60", OutOfRangeTzMinute;
@@ -1118,6 +2173,223 @@ fn duration_comparison() {
     assert!(d8 < d7);
 }
 
+#[test]
+fn duration_millis() {
+    let d = Duration::from_millis(-1_500).unwrap();
+    assert_eq!(
+        d,
+        Duration {
+            positive: false,
+            day: 0,
+            second: 1,
+            microsecond: 500_000,
+        }
+    );
+    assert_eq!(d.as_millis(), -1_500);
+
+    // 10 days, 1 hour and 1 millisecond
+    let d = Duration::from_millis(867_600_001).unwrap();
+    assert_eq!(d.to_string(), "P10DT1H0.001S");
+    assert_eq!(d.as_millis(), 867_600_001);
+
+    assert_eq!(Duration::from_millis(0).unwrap(), Duration::ZERO);
+    assert_eq!(Duration::ZERO.as_millis(), 0);
+
+    for millis in [
+        1,
+        -1,
+        999,
+        86_399_999,
+        86_400_000,
+        -86_400_001,
+        123_456_789_012,
+        -123_456_789_012,
+    ] {
+        assert_eq!(Duration::from_millis(millis).unwrap().as_millis(), millis, "{millis}");
+    }
+
+    // sub-millisecond precision is truncated
+    assert_eq!(Duration::parse_str("PT0.0019S").unwrap().as_millis(), 1);
+    assert_eq!(Duration::parse_str("-PT0.0019S").unwrap().as_millis(), -1);
+
+    assert_eq!(Duration::MAX.as_millis(), 86_399_999_999_999_999);
+    assert_eq!(
+        Duration::from_millis(86_399_999_999_999_999).unwrap().to_string(),
+        "P2739726Y9DT23H59M59.999S"
+    );
+    assert_eq!(
+        Duration::from_millis(86_400_000_000_000_000).unwrap_err(),
+        ParseError::DurationDaysTooLarge
+    );
+    assert_eq!(
+        Duration::from_millis(i64::MIN).unwrap_err(),
+        ParseError::DurationDaysTooLarge
+    );
+}
+
+#[test]
+fn duration_seconds_f64() {
+    let d = Duration::from_seconds_f64(1.5).unwrap();
+    assert_eq!(
+        d,
+        Duration {
+            positive: true,
+            day: 0,
+            second: 1,
+            microsecond: 500_000,
+        }
+    );
+    assert_eq!(d.as_seconds_f64(), 1.5);
+
+    let d = Duration::from_seconds_f64(-0.000001).unwrap();
+    assert_eq!(
+        d,
+        Duration {
+            positive: false,
+            day: 0,
+            second: 0,
+            microsecond: 1,
+        }
+    );
+    assert_eq!(d.as_seconds_f64(), -0.000001);
+
+    let d = Duration::from_seconds_f64(86_400.0 * 999_999_999.0 + 86_399.0).unwrap();
+    assert_eq!(
+        d,
+        Duration {
+            positive: true,
+            day: 999_999_999,
+            second: 86_399,
+            microsecond: 0,
+        }
+    );
+    assert_eq!(d.as_seconds_f64(), 86_399_999_999_999.0);
+    assert_eq!(
+        Duration::from_seconds_f64(-86_400.0 * 1_000_000_000.0).unwrap_err(),
+        ParseError::DurationDaysTooLarge
+    );
+    assert_eq!(
+        Duration::from_seconds_f64(f64::INFINITY).unwrap_err(),
+        ParseError::DurationDaysTooLarge
+    );
+    assert_eq!(
+        Duration::from_seconds_f64(f64::NAN).unwrap_err(),
+        ParseError::DurationInvalidNumber
+    );
+
+    // sub-microsecond values are rounded, zero is always positive
+    assert_eq!(Duration::from_seconds_f64(-0.0000001).unwrap(), Duration::ZERO);
+    assert_eq!(Duration::from_seconds_f64(0.9999999).unwrap().to_string(), "PT1S");
+    assert_eq!(Duration::from_seconds_f64(86_399.9999999).unwrap().to_string(), "P1D");
+
+    for s in ["PT0S", "P1DT2.5S", "-P10DT1H", "-PT0.000001S", "P100YT1.123456S"] {
+        let d = Duration::parse_str(s).unwrap();
+        assert_eq!(Duration::from_seconds_f64(d.as_seconds_f64()).unwrap(), d, "{s}");
+    }
+}
+
+#[test]
+fn duration_cmp_magnitude() {
+    use std::cmp::Ordering;
+    let duration = |s| Duration::parse_str(s).unwrap();
+    assert!(duration("-P2D") < duration("+P1D"));
+    assert_eq!(duration("-P2D").cmp_magnitude(&duration("+P1D")), Ordering::Greater);
+    assert_eq!(duration("+P1D").cmp_magnitude(&duration("-P2D")), Ordering::Less);
+    assert_eq!(duration("-P1D").cmp_magnitude(&duration("+P1D")), Ordering::Equal);
+    assert_eq!(duration("-PT1S").cmp_magnitude(&duration("-PT2S")), Ordering::Less);
+    assert_eq!(
+        duration("PT1.5S").cmp_magnitude(&duration("-PT1.4S")),
+        Ordering::Greater
+    );
+
+    let mut durations = [duration("P3D"), duration("-P2D"), duration("PT1H"), duration("-PT30M")];
+    durations.sort_by(|a, b| a.cmp_magnitude(b));
+    let durations: Vec<String> = durations.iter().map(ToString::to_string).collect();
+    assert_eq!(durations, ["-PT30M", "PT1H", "-P2D", "P3D"]);
+}
+
+#[test]
+fn duration_clamp() {
+    let duration = |s| Duration::parse_str(s).unwrap();
+    let (min, max) = (duration("-PT1H"), duration("P1D"));
+    for (input, expected) in [
+        ("P2D", "P1D"),
+        ("P1DT1S", "P1D"),
+        ("P1D", "P1D"),
+        ("PT12H", "PT12H"),
+        ("PT0S", "PT0S"),
+        ("-PT30M", "-PT30M"),
+        ("-PT1H", "-PT1H"),
+        ("-PT2H", "-PT1H"),
+        ("-P3D", "-PT1H"),
+    ] {
+        assert_eq!(duration(input).clamp(&min, &max).to_string(), expected, "{input:?}");
+    }
+
+    // a longer negative duration is less, so it's clamped to the shorter negative bound
+    let (min, max) = (duration("-P2D"), duration("-P1D"));
+    assert_eq!(duration("-P3D").clamp(&min, &max).to_string(), "-P2D");
+    assert_eq!(duration("-PT1H").clamp(&min, &max).to_string(), "-P1D");
+    assert_eq!(duration("PT1H").clamp(&min, &max).to_string(), "-P1D");
+
+    assert_eq!(duration_min(&duration("-P2D"), &duration("P1D")).to_string(), "-P2D");
+    assert_eq!(duration_max(&duration("-P2D"), &duration("P1D")).to_string(), "P1D");
+    assert_eq!(duration_min(&duration("-P2D"), &duration("-P1D")).to_string(), "-P2D");
+    assert_eq!(duration_max(&duration("-P2D"), &duration("-P1D")).to_string(), "-P1D");
+    assert_eq!(duration_max(&Duration::MIN, &Duration::MAX), Duration::MAX);
+    assert_eq!(duration_min(&Duration::MAX, &Duration::MIN), Duration::MIN);
+}
+
+#[test]
+fn duration_saturating_add_sub() {
+    let duration = |s| Duration::parse_str(s).unwrap();
+    for (a, b, sum, difference) in [
+        ("P1D", "PT1H", "P1DT1H", "PT23H"),
+        ("PT0.5S", "PT0.5S", "PT1S", "PT0S"),
+        ("-P1D", "PT1H", "-PT23H", "-P1DT1H"),
+        ("-PT1S", "-PT1S", "-PT2S", "PT0S"),
+        ("PT0S", "PT0S", "PT0S", "PT0S"),
+    ] {
+        assert_eq!(duration(a).saturating_add(&duration(b)).to_string(), sum, "{a} + {b}");
+        assert_eq!(
+            duration(a).saturating_sub(&duration(b)).to_string(),
+            difference,
+            "{a} - {b}"
+        );
+    }
+
+    // near the limits, results are clamped
+    let near_max = Duration::new(true, 999_999_999, 0, 0).unwrap();
+    assert_eq!(near_max.saturating_add(&duration("P1D")), Duration::MAX);
+    assert_eq!(near_max.saturating_add(&near_max), Duration::MAX);
+    assert_eq!(near_max.saturating_sub(&Duration::MIN), Duration::MAX);
+    assert_eq!(
+        near_max.saturating_add(&duration("PT1H")),
+        Duration::new(true, 999_999_999, 3_600, 0).unwrap()
+    );
+    let near_min = Duration::new(false, 999_999_999, 0, 0).unwrap();
+    assert_eq!(near_min.saturating_sub(&duration("P1D")), Duration::MIN);
+    assert_eq!(near_min.saturating_add(&near_min), Duration::MIN);
+    assert_eq!(Duration::MAX.saturating_add(&Duration::MIN), Duration::ZERO);
+    assert_eq!(Duration::MAX.saturating_sub(&Duration::MAX), Duration::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "min must be less than or equal to max")]
+fn duration_clamp_invalid_bounds() {
+    Duration::ZERO.clamp(&Duration::MAX, &Duration::MIN);
+}
+
+#[test]
+fn duration_as_dhms_micros() {
+    let d = Duration::parse_str("P1Y63DT4H5M6.5S").unwrap();
+    assert_eq!(d.as_dhms_micros(), (true, 428, 4, 5, 6, 500_000));
+    let d = Duration::parse_str("-PT25H").unwrap();
+    assert_eq!(d.as_dhms_micros(), (false, 1, 1, 0, 0, 0));
+    let d = Duration::parse_str("PT0S").unwrap();
+    assert_eq!(d.as_dhms_micros(), (true, 0, 0, 0, 0, 0));
+}
+
 #[test]
 fn duration_new_err() {
     let d = Duration::new(true, u32::MAX, 4294967295, 905969663);
@@ -1132,6 +2404,47 @@ fn duration_new_err() {
     }
 }
 
+#[test]
+fn duration_consts() {
+    assert_eq!(
+        Duration::MAX,
+        Duration::new(true, 999_999_999, 86_399, 999_999).unwrap()
+    );
+    assert_eq!(
+        Duration::MIN,
+        Duration::new(false, 999_999_999, 86_399, 999_999).unwrap()
+    );
+    assert_eq!(Duration::ZERO, Duration::new(true, 0, 0, 0).unwrap());
+    assert_eq!(Duration::MAX.to_string(), "P2739726Y9DT23H59M59.999999S");
+    assert_eq!(Duration::MIN.to_string(), "-P2739726Y9DT23H59M59.999999S");
+    assert_eq!(Duration::ZERO.to_string(), "PT0S");
+    assert_eq!(
+        Duration::parse_str("P2739726Y9DT23H59M59.999999S").unwrap(),
+        Duration::MAX
+    );
+    assert_eq!(
+        Duration::parse_str("-P2739726Y9DT23H59M59.999999S").unwrap(),
+        Duration::MIN
+    );
+    assert!(Duration::MIN < Duration::ZERO);
+    assert!(Duration::ZERO < Duration::MAX);
+
+    // adding a single microsecond to the max duration is too large
+    let max = Duration::MAX;
+    match Duration::new(true, max.day, max.second, max.microsecond + 1) {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::DurationDaysTooLarge),
+    }
+    match Duration::parse_str("P2739726Y10D") {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::DurationDaysTooLarge),
+    }
+    match DateTime::MIN.add_duration(&Duration::MAX) {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::DateTooLarge),
+    }
+}
+
 #[test]
 fn duration_hours() {
     let d = Duration::parse_str("PT5H45M").unwrap();
@@ -1198,6 +2511,14 @@ param_tests! {
     duration: err => "P1DT1X", DurationInvalidTimeUnit;
     duration_invalid_day_unit1: err => "P1X", DurationInvalidDateUnit;
     duration_invalid_day_unit2: err => "P1", DurationInvalidDateUnit;
+    duration_hour_before_t: err => "P1H", DurationTimeUnitBeforeT;
+    duration_second_before_t: err => "P1S", DurationTimeUnitBeforeT;
+    duration_hour_after_day_before_t: err => "P1D2H", DurationTimeUnitBeforeT;
+    duration_fraction_second_before_t: err => "P1.5S", DurationTimeUnitBeforeT;
+    duration_week: ok => "P1W", "P7D";
+    duration_week_mixed: ok => "P1Y2W3DT4H", "P1Y17DT4H";
+    duration_week_after_t: err => "PT1W", DurationWeekAfterT;
+    duration_week_after_t_hours: err => "P1DT2H1W", DurationWeekAfterT;
     duration_time_42s: ok => "00:00:42", "PT42S";
     duration_time_42s_no_leading_0: ok => "0:00:42", "PT42S";
     duration_time_1m: ok => "00:01", "PT1M";
@@ -1248,6 +2569,51 @@ param_tests! {
     duration_overflow: err => "18446744073709551616 day 12:00", DurationValueTooLarge;
     duration_fuzz1: err => "P18446744073709551611DT8031M1M1M1M", DurationValueTooLarge;
     duration_fuzz2: err => "P18446744073709550PT9970442H6R15D1D", DurationValueTooLarge;
+    duration_fraction_day_micro: ok => "P0.9999999999D", "PT23H59M59.999991S";
+    duration_fraction_day_micro_carry: ok => "P1.99999999999999D", "P2D";
+    duration_fraction_year_micro: ok => "P0.99999999999999Y", "P1Y";
+    duration_fraction_day_too_large: err => "P4294967295.9999999D", DurationDaysTooLarge;
+    duration_alternative: ok => "P0001-02-03T04:05:06", "P1Y63DT4H5M6S";
+    duration_alternative_fraction: ok => "P0001-02-03T04:05:06.5", "P1Y63DT4H5M6.5S";
+    duration_alternative_date_only: ok => "P0003-06-04", "P3Y184D";
+    duration_alternative_no_seconds: ok => "P0000-00-00T12:30", "PT12H30M";
+    duration_alternative_zero: ok => "P0000-00-00T00:00:00", "PT0S";
+    duration_alternative_neg: ok => "-P0000-00-01T00:00:01", "-P1DT1S";
+    duration_alternative_short: err => "P0001-02-0", TooShort;
+    duration_alternative_year: err => "P000x-02-03", DurationInvalidDateUnit;
+    duration_alternative_month: err => "P0001-x2-03", InvalidCharMonth;
+    duration_alternative_day: err => "P0001-02-x3", InvalidCharDay;
+    duration_alternative_sep: err => "P0001-02x03", InvalidCharDateSep;
+    duration_alternative_month_range: err => "P0001-13-03", OutOfRangeMonth;
+    duration_alternative_day_range: err => "P0001-02-31", OutOfRangeDay;
+    duration_alternative_t_sep: err => "P0001-02-03X04:05:06", ExtraCharacters;
+    duration_alternative_time_short: err => "P0001-02-03T04", TooShort;
+    duration_alternative_hour_range: err => "P0001-02-03T24:00:00", OutOfRangeHour;
+    duration_alternative_extra: err => "P0001-02-03T04:05:06x", ExtraCharacters;
+}
+
+#[test]
+fn duration_alternative_format() {
+    let d = Duration::parse_str("P0001-02-03T04:05:06").unwrap();
+    assert_eq!(
+        d,
+        Duration {
+            positive: true,
+            day: 365 + 2 * 30 + 3,
+            second: 4 * 3_600 + 5 * 60 + 6,
+            microsecond: 0,
+        }
+    );
+    assert_eq!(d, Duration::parse_str("P1Y2M3DT4H5M6S").unwrap());
+
+    // only `P` followed by four digits and `-` is the alternative format, other errors are unchanged
+    for (input, error) in [
+        ("PT1.5-", ParseError::DurationInvalidTimeUnit),
+        ("P1Y2M-W3D", ParseError::DurationInvalidNumber),
+        ("P12T4-", ParseError::DurationInvalidDateUnit),
+    ] {
+        assert_eq!(Duration::parse_str(input), Err(error), "{input:?}");
+    }
 }
 
 #[test]
@@ -1476,34 +2842,2245 @@ fn test_time_parse_unix_timestamp_from_bytes_as_naive() {
 }
 
 #[test]
-fn test_time_config_builder() {
+fn test_datetime_float_rounding() {
+    let parse = |s: &str, mode: RoundingMode| {
+        let config = TimeConfigBuilder::new()
+            .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Truncate)
+            .float_rounding(mode)
+            .build();
+        DateTime::parse_bytes_with_config(s.as_bytes(), &config)
+            .unwrap()
+            .to_string()
+    };
+    // as an f64 this is 1654646404.12345647811...
     assert_eq!(
-        TimeConfigBuilder::new().build(),
-        TimeConfig {
-            microseconds_precision_overflow_behavior: MicrosecondsPrecisionOverflowBehavior::Error,
-            unix_timestamp_offset: None,
-        }
+        parse("1654646404.1234565", RoundingMode::Round),
+        "2022-06-08T00:00:04.123456"
     );
-    assert_eq!(TimeConfigBuilder::new().build(), TimeConfig::builder().build());
+    assert_eq!(
+        parse("1654646404.1234565", RoundingMode::Truncate),
+        "2022-06-08T00:00:04.123456"
+    );
+    assert_eq!(
+        parse("1654646404.1234565", RoundingMode::Ceil),
+        "2022-06-08T00:00:04.123457"
+    );
+    assert_eq!(
+        parse("1654646404.1234567", RoundingMode::Round),
+        "2022-06-08T00:00:04.123457"
+    );
+    assert_eq!(
+        parse("1654646404.1234567", RoundingMode::Truncate),
+        "2022-06-08T00:00:04.123456"
+    );
+    assert_eq!(
+        parse("1654646404.1234567", RoundingMode::Ceil),
+        "2022-06-08T00:00:04.123457"
+    );
+    // negative timestamps are rounded in the same direction, since microseconds count forward from the second
+    assert_eq!(parse("-1.1234567", RoundingMode::Round), "1969-12-31T23:59:58.876543");
+    assert_eq!(
+        parse("-1.1234567", RoundingMode::Truncate),
+        "1969-12-31T23:59:58.876543"
+    );
+    assert_eq!(parse("-1.1234567", RoundingMode::Ceil), "1969-12-31T23:59:58.876544");
+
+    assert_eq!(TimeConfigBuilder::new().build().float_rounding, RoundingMode::Round);
+    assert_eq!(RoundingMode::try_from("Truncate").unwrap(), RoundingMode::Truncate);
+    assert_eq!(RoundingMode::try_from("ceil").unwrap(), RoundingMode::Ceil);
+    assert!(RoundingMode::try_from("floor").is_err());
 }
 
 #[test]
-fn date_dash_err() {
-    let error = Date::parse_str("-").unwrap_err();
-    assert_eq!(error, ParseError::TooShort);
-    assert_eq!(error.to_string(), "too_short");
-    assert_eq!(error.get_documentation(), Some("input is too short"));
+fn test_datetime_float_rounding_carry() {
+    let config = TimeConfigBuilder::new()
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Truncate)
+        .build();
+    let dt = DateTime::parse_bytes_with_config(b"1654646404.9999999", &config).unwrap();
+    assert_eq!(dt.time.second, 5);
+    assert_eq!(dt.time.microsecond, 0);
+    assert_eq!(dt.to_string(), "2022-06-08T00:00:05");
+
+    let dt = DateTime::parse_bytes_with_config(b"1654646399.9999999", &config).unwrap();
+    assert_eq!(dt.to_string(), "2022-06-08T00:00:00");
+
+    let dt = DateTime::parse_bytes_with_config(b"-0.0000001", &config).unwrap();
+    assert_eq!(dt.to_string(), "1970-01-01T00:00:00");
+
+    let config = TimeConfigBuilder::new()
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Truncate)
+        .float_rounding(RoundingMode::Ceil)
+        .build();
+    let dt = DateTime::parse_bytes_with_config(b"1654646404.9999999", &config).unwrap();
+    assert_eq!(dt.to_string(), "2022-06-08T00:00:05");
 }
 
 #[test]
-fn number_dash_err() {
-    assert!(int_parse_str("-").is_none());
-    assert!(int_parse_str("+").is_none());
-    assert!(int_parse_bytes(b"-").is_none());
-    assert!(int_parse_bytes(b"+").is_none());
+fn test_time_single_digit_fields() {
+    let config = TimeConfigBuilder::new().allow_single_digit_fields(true).build();
+    for (input, expected) in [
+        ("2:3:4", "02:03:04"),
+        ("2:3", "02:03:00"),
+        ("12:3:45", "12:03:45"),
+        ("2:13:14", "02:13:14"),
+        ("02:03:04", "02:03:04"),
+        ("2:3:4.5", "02:03:04.500000"),
+        ("2:3:4Z", "02:03:04Z"),
+        ("2:3:4+01:00", "02:03:04+01:00"),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.to_string(), expected, "{input:?}");
+    }
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01T2:3:4Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T02:03:04Z");
+    for (input, error) in [
+        ("2:", ParseError::TooShort),
+        ("2:x", ParseError::InvalidCharMinute),
+        ("2-3", ParseError::InvalidCharTimeSep),
+        ("123:4", ParseError::InvalidCharTimeSep),
+        ("25:3", ParseError::OutOfRangeHour),
+        ("2:60", ParseError::OutOfRangeMinute),
+        ("2:3:60", ParseError::OutOfRangeSecond),
+        ("2:3:4x", ParseError::InvalidCharTzSign),
+        ("2: 3", ParseError::UnexpectedWhitespace),
+        ("2x :3", ParseError::InvalidCharTimeSep),
+        ("2:3: 4", ParseError::UnexpectedWhitespace),
+    ] {
+        match Time::parse_bytes_with_config(input.as_bytes(), &config) {
+            Ok(t) => panic!("unexpectedly valid: {input:?} -> {t:?}"),
+            Err(e) => assert_eq!(e, error, "{input:?}"),
+        }
+    }
 
-    assert!(matches!(float_parse_str("-"), IntFloat::Err));
-    assert!(matches!(float_parse_str("+"), IntFloat::Err));
-    assert!(matches!(float_parse_bytes(b"-"), IntFloat::Err));
-    assert!(matches!(float_parse_bytes(b"+"), IntFloat::Err));
+    // by default single digit fields are rejected
+    for input in ["2:3:4", "2:3", "2:13:14", "12:3:45", "12:13:4", "12:13:4Z", "12:13:4.5"] {
+        match Time::parse_str(input) {
+            Ok(t) => panic!("unexpectedly valid: {input:?} -> {t:?}"),
+            Err(e) => assert_eq!(e, ParseError::TimeFieldNotPadded, "{input:?}"),
+        }
+    }
+    match DateTime::parse_str("2020-01-01T2:13:14") {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::TimeFieldNotPadded),
+    }
+}
+
+#[test]
+fn test_trim_whitespace() {
+    let config = TimeConfigBuilder::new().trim_whitespace(true).build();
+    let dt = DateTime::parse_bytes_with_config(b" 2020-01-01T12:13:14Z\n", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
+    let dt = DateTime::parse_bytes_rfc3339_with_config(b"\t2020-01-01T12:13:14\r\n", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14");
+    let dt = DateTime::parse_bytes_with_config(b" 1654646404 ", &config).unwrap();
+    assert_eq!(dt.to_string(), "2022-06-08T00:00:04");
+    let t = Time::parse_bytes_with_config(b"12:13:14\r", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:14");
+    let d = Duration::parse_bytes_with_config(b" P1D ", &config).unwrap();
+    assert_eq!(d.to_string(), "P1D");
+    match DateTime::parse_bytes_with_config(b"2020-01-01 T12:13:14Z", &config) {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::InvalidCharHour),
+    }
+
+    // whitespace is an error by default
+    let config = TimeConfigBuilder::new().build();
+    match DateTime::parse_bytes_with_config(b" 2020-01-01T12:13:14Z\n", &config) {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::InvalidCharYear),
+    }
+    match DateTime::parse_bytes_with_config(b"2020-01-01T12:13:14Z\n", &config) {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::ExtraCharacters),
+    }
+    match Time::parse_bytes_with_config(b"12:13:14 ", &config) {
+        Ok(t) => panic!("unexpectedly valid: {t:?}"),
+        Err(e) => assert_eq!(e, ParseError::InvalidCharTzSign),
+    }
+}
+
+#[test]
+fn test_space_before_offset() {
+    let config = TimeConfigBuilder::new().allow_space_before_offset(true).build();
+    let t = Time::parse_bytes_with_config(b"12:13:14 +02:00", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:14+02:00");
+    let t = Time::parse_bytes_with_config(b"12:13:14.5 -01:30", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:14.500000-01:30");
+    let t = Time::parse_bytes_with_config(b"12:13 Z", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:00Z");
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01 12:13:14 +02:00", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14+02:00");
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01 12:13:14+02:00", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14+02:00");
+    // only a single space is allowed, and it must be followed by an offset
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13:14  +02:00", &config).unwrap_err(),
+        ParseError::InvalidCharTzSign
+    );
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13:14 ", &config).unwrap_err(),
+        ParseError::InvalidCharTzSign
+    );
+
+    // by default the space is rejected
+    let config = TimeConfigBuilder::new().build();
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13:14 +02:00", &config).unwrap_err(),
+        ParseError::InvalidCharTzSign
+    );
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"2020-01-01 12:13:14 +02:00", &config).unwrap_err(),
+        ParseError::InvalidCharTzSign
+    );
+}
+
+#[test]
+fn test_tz_names() {
+    let config = TimeConfigBuilder::new()
+        .allow_space_before_offset(true)
+        .allow_tz_names(true)
+        .build();
+    let t = Time::parse_bytes_with_config(b"12:13:14 UTC", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:14Z");
+    assert_eq!(t.tz_offset, Some(0));
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01T12:13:14GMT", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01 12:13:14.5 utc", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14.500000Z");
+    let t = Time::parse_bytes_with_config(b"12:13UT", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:00Z");
+    // offsets are still supported
+    let t = Time::parse_bytes_with_config(b"12:13:14+01:00", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:14+01:00");
+
+    for (input, error) in [
+        ("12:13:14 EST", ParseError::UnknownTzName),
+        ("12:13:14UTCX", ParseError::UnknownTzName),
+        ("12:13:14 UTC+01:00", ParseError::DuplicateTimezone),
+        ("12:13:14UTC ", ParseError::ExtraCharacters),
+    ] {
+        match Time::parse_bytes_with_config(input.as_bytes(), &config) {
+            Ok(t) => panic!("unexpectedly valid: {input:?} -> {t:?}"),
+            Err(e) => assert_eq!(e, error, "{input:?}"),
+        }
+    }
+
+    // by default names are rejected
+    assert_eq!(
+        DateTime::parse_str("2020-01-01T12:13:14GMT").unwrap_err(),
+        ParseError::InvalidCharTzSign
+    );
+}
+
+#[test]
+fn test_tz_offset_seconds() {
+    let config = TimeConfigBuilder::new().allow_tz_offset_seconds(true).build();
+    let t = Time::parse_bytes_with_config(b"12:13:14+00:09:21", &config).unwrap();
+    assert_eq!(t.tz_offset, Some(561));
+    let t = Time::parse_bytes_with_config(b"12:13:14-01:02:03", &config).unwrap();
+    assert_eq!(t.tz_offset, Some(-3723));
+    let t = Time::parse_bytes_with_config(b"12:13:14+01:00", &config).unwrap();
+    assert_eq!(t.tz_offset, Some(3600));
+    let dt = DateTime::parse_bytes_with_config(b"1900-01-01T12:13:14+00:09:21", &config).unwrap();
+    assert_eq!(dt.time.tz_offset, Some(561));
+    assert_eq!(dt.timestamp_tz(), dt.timestamp() - 561);
+
+    for (input, error) in [
+        ("12:13:14+00:09:2", ParseError::InvalidCharTzSecond),
+        ("12:13:14+00:09:x1", ParseError::InvalidCharTzSecond),
+        ("12:13:14+00:09:60", ParseError::OutOfRangeTzSecond),
+        ("12:13:14+23:59:60", ParseError::OutOfRangeTzSecond),
+        ("12:13:14+00:09:21x", ParseError::ExtraCharacters),
+    ] {
+        match Time::parse_bytes_with_config(input.as_bytes(), &config) {
+            Ok(t) => panic!("unexpectedly valid: {input:?} -> {t:?}"),
+            Err(e) => assert_eq!(e, error, "{input:?}"),
+        }
+    }
+
+    // by default seconds are rejected
+    assert_eq!(
+        Time::parse_str("12:13:14+00:09:21").unwrap_err(),
+        ParseError::TzOffsetSecondsNotAllowed
+    );
+    assert_eq!(
+        DateTime::parse_str("1900-01-01T12:13:14+00:09:21").unwrap_err(),
+        ParseError::TzOffsetSecondsNotAllowed
+    );
+}
+
+#[test]
+fn test_fractional_minutes() {
+    let config = TimeConfigBuilder::new().allow_fractional_minutes(true).build();
+    for (input, expected) in [
+        ("12:13.5", "12:13:30"),
+        ("12:13,25", "12:13:15"),
+        ("12:13.123456", "12:13:07.407360"),
+        ("12:13.999999", "12:13:59.999940"),
+        ("12:13.5Z", "12:13:30Z"),
+        ("12:13.5+01:00", "12:13:30+01:00"),
+        ("12:13:14.5", "12:13:14.500000"),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.to_string(), expected, "{input:?}");
+    }
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01T12:13.5Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:30Z");
+    let config_single_digit = TimeConfigBuilder::new()
+        .allow_fractional_minutes(true)
+        .allow_single_digit_fields(true)
+        .build();
+    let t = Time::parse_bytes_with_config(b"2:3.5", &config_single_digit).unwrap();
+    assert_eq!(t.to_string(), "02:03:30");
+
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13.", &config).unwrap_err(),
+        ParseError::SecondFractionMissing
+    );
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13.1234567", &config).unwrap_err(),
+        ParseError::SecondFractionTooLong
+    );
+
+    // by default fractional minutes are rejected
+    assert_eq!(Time::parse_str("12:13.5").unwrap_err(), ParseError::InvalidCharTzSign);
+}
+
+#[test]
+fn duration_dotnet_timespan() {
+    let config = DurationConfigBuilder::new().allow_dotnet_timespan(true).build();
+    for (input, expected) in [
+        ("1.12:30:00", "P1DT12H30M"),
+        ("-0.00:00:01", "-PT1S"),
+        ("+2.01:02:03", "P2DT1H2M3S"),
+        ("10.00:00:00.5", "P10DT0.5S"),
+        ("12:30:00", "PT12H30M"),
+        ("12:30:00.123456", "PT12H30M0.123456S"),
+    ] {
+        let d = Duration::parse_bytes_with_duration_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(d.to_string(), expected, "{input:?}");
+    }
+
+    for (input, error) in [
+        ("1.2x:30:00", ParseError::InvalidCharHour),
+        ("1.24:00:00", ParseError::DurationHourValueTooLarge),
+        ("1.12:30:00:00", ParseError::ExtraCharacters),
+        ("1.12:60:00", ParseError::OutOfRangeMinute),
+        ("1a.12:30:00", ParseError::DurationInvalidDays),
+    ] {
+        let result = Duration::parse_bytes_with_duration_config(input.as_bytes(), &config);
+        assert_eq!(result.unwrap_err(), error, "{input:?}");
+    }
+
+    // by default the days prefix is rejected
+    assert_eq!(
+        Duration::parse_str("1.12:30:00").unwrap_err(),
+        ParseError::InvalidCharHour
+    );
+}
+
+#[test]
+fn datetime_parse_with_meta() {
+    let config = TimeConfigBuilder::new().build();
+    for (input, tz_offset, source) in [
+        ("2022-01-01T12:13:14Z", Some(0), TzSource::Zulu),
+        ("2022-01-01T12:13:14z", Some(0), TzSource::Zulu),
+        ("2022-01-01T12:13:14.123Z", Some(0), TzSource::Zulu),
+        ("2022-01-01T12:13:14+00:00", Some(0), TzSource::Offset),
+        ("2022-01-01T12:13:14-00:00", Some(0), TzSource::Offset),
+        ("2022-01-01T12:13:14+0100", Some(3600), TzSource::Offset),
+        ("2022-01-01T12:13:14", None, TzSource::Naive),
+        ("1641039194", None, TzSource::Naive),
+    ] {
+        let (dt, tz_source) = DateTime::parse_bytes_with_meta(input.as_bytes(), &config).unwrap();
+        assert_eq!(dt.time.tz_offset, tz_offset, "{input:?}");
+        assert_eq!(tz_source, source, "{input:?}");
+    }
+
+    let config = TimeConfigBuilder::new()
+        .allow_tz_names(true)
+        .unix_timestamp_offset(Some(0))
+        .trim_whitespace(true)
+        .build();
+    for (input, source) in [
+        ("2022-01-01T12:13:14UTC", TzSource::Zulu),
+        (" 2022-01-01T12:13:14Z\n", TzSource::Zulu),
+        ("1641039194", TzSource::Offset),
+    ] {
+        let (_, tz_source) = DateTime::parse_bytes_with_meta(input.as_bytes(), &config).unwrap();
+        assert_eq!(tz_source, source, "{input:?}");
+    }
+
+    assert_eq!(
+        DateTime::parse_bytes_with_meta(b"2022-01-01T12:13:14+", &config).unwrap_err(),
+        ParseError::InvalidCharTzHour
+    );
+}
+
+#[test]
+fn test_time_parse_round_fraction() {
+    let config = TimeConfigBuilder::new()
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Round)
+        .build();
+    for (input, expected) in [
+        ("12:13:14.1234567", "12:13:14.123457"),
+        ("12:13:14.1234565", "12:13:14.123457"),
+        ("12:13:14.1234564", "12:13:14.123456"),
+        ("12:13:14.12345649", "12:13:14.123456"),
+        ("12:13:14.123456", "12:13:14.123456"),
+        ("12:13:14.9999999", "12:13:15"),
+        ("12:13:59.9999995Z", "12:14:00Z"),
+        ("12:59:59.9999999+01:00", "13:00:00+01:00"),
+        ("23:59:59.9999994", "23:59:59.999999"),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.to_string(), expected, "{input:?}");
+    }
+    // rounding up past the end of the day is an error without `allow_end_of_day_24`
+    assert_eq!(
+        Time::parse_bytes_with_config(b"23:59:59.9999999", &config).unwrap_err(),
+        ParseError::TimeTooLarge
+    );
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"2020-12-31T23:59:59.9999999Z", &config).unwrap_err(),
+        ParseError::TimeTooLarge
+    );
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"9999-12-31T23:59:59.9999999", &config).unwrap_err(),
+        ParseError::TimeTooLarge
+    );
+    assert_eq!(
+        Duration::parse_bytes_with_config(b"P0000-00-00T23:59:59.9999999", &config).unwrap_err(),
+        ParseError::TimeTooLarge
+    );
+    // other durations aren't limited to a day
+    let d = Duration::parse_bytes_with_config(b"23:59:59.9999999", &config).unwrap();
+    assert_eq!(d.to_string(), "P1D");
+
+    // rounding up to the end of the day requires `allow_end_of_day_24`
+    let config = TimeConfigBuilder::new()
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Round)
+        .allow_end_of_day_24(true)
+        .build();
+    let t = Time::parse_bytes_with_config(b"23:59:59.9999999", &config).unwrap();
+    assert_eq!(t.to_string(), "00:00:00");
+    let dt = DateTime::parse_bytes_with_config(b"2020-12-31T23:59:59.9999999Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2021-01-01T00:00:00Z");
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"9999-12-31T23:59:59.9999999", &config).unwrap_err(),
+        ParseError::DateTooLarge
+    );
+    let d = Duration::parse_bytes_with_config(b"P0000-00-00T23:59:59.9999999", &config).unwrap();
+    assert_eq!(d.to_string(), "P1D");
+    let d = Duration::parse_bytes_with_config(b"00:00:01.9999999", &config).unwrap();
+    assert_eq!(d.to_string(), "PT2S");
+    let d = Duration::parse_bytes_with_config(b"00:00:01.1234565", &config).unwrap();
+    assert_eq!(d.to_string(), "PT1.123457S");
+
+    // truncate and error are unchanged
+    let config = TimeConfigBuilder::new()
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Truncate)
+        .build();
+    let t = Time::parse_bytes_with_config(b"12:13:14.9999999", &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:14.999999");
+    assert_eq!(
+        Time::parse_str("12:13:14.1234565").unwrap_err(),
+        ParseError::SecondFractionTooLong
+    );
+
+    assert_eq!(
+        MicrosecondsPrecisionOverflowBehavior::try_from("round").unwrap(),
+        MicrosecondsPrecisionOverflowBehavior::Round
+    );
+}
+
+#[test]
+fn date_min_timestamp_digits() {
+    // by default short numbers are timestamps
+    let d = Date::parse_bytes_with_config(b"0", &DateConfigBuilder::new().build()).unwrap();
+    assert_eq!(d.to_string(), "1970-01-01");
+    assert_eq!(Date::parse_str("12").unwrap_err(), ParseError::DateNotExact);
+
+    let config = DateConfigBuilder::new().min_timestamp_digits(Some(5)).build();
+    for input in ["12", "0", "+1234", "-1234"] {
+        let result = Date::parse_bytes_with_config(input.as_bytes(), &config);
+        assert_eq!(result.unwrap_err(), ParseError::TooShort, "{input:?}");
+    }
+    assert_eq!(
+        Date::parse_bytes_with_config(b"00000", &config).unwrap().to_string(),
+        "1970-01-01"
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"1654560000", &config)
+            .unwrap()
+            .to_string(),
+        "2022-06-07"
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"2022-06-07", &config)
+            .unwrap()
+            .to_string(),
+        "2022-06-07"
+    );
+    assert_eq!(
+        Date::parse_bytes_with_config(b"1234567890x", &config).unwrap_err(),
+        ParseError::InvalidCharDateSep
+    );
+}
+
+fn calendar_diff(a: &str, b: &str) -> CalendarDelta {
+    let a = DateTime::parse_str(a).unwrap();
+    let b = DateTime::parse_str(b).unwrap();
+    a.calendar_diff(&b).unwrap()
+}
+
+#[test]
+fn datetime_calendar_diff() {
+    for (a, b, positive, ymd) in [
+        // exactly a year apart
+        ("2021-02-28T00:00:00", "2020-02-28T00:00:00", true, (1, 0, 0)),
+        ("2020-02-28T00:00:00", "2019-02-28T00:00:00", true, (1, 0, 0)),
+        ("2021-02-28T00:00:00", "2020-02-29T00:00:00", true, (1, 0, 0)),
+        ("2020-02-29T00:00:00", "2019-02-28T00:00:00", true, (1, 0, 1)),
+        ("2020-02-28T00:00:00", "2021-02-28T00:00:00", false, (1, 0, 0)),
+        // a month apart with differing month lengths
+        ("2021-02-28T00:00:00", "2021-01-31T00:00:00", true, (0, 1, 0)),
+        ("2021-02-27T00:00:00", "2021-01-31T00:00:00", true, (0, 0, 27)),
+        ("2020-02-29T00:00:00", "2020-01-31T00:00:00", true, (0, 1, 0)),
+        ("2021-03-01T00:00:00", "2021-01-31T00:00:00", true, (0, 1, 1)),
+        ("2021-05-30T00:00:00", "2021-04-30T00:00:00", true, (0, 1, 0)),
+        ("2021-05-31T00:00:00", "2021-04-30T00:00:00", true, (0, 1, 1)),
+        ("2022-01-15T00:00:00", "2021-12-15T00:00:00", true, (0, 1, 0)),
+        ("2021-12-15T00:00:00", "2022-01-15T00:00:00", false, (0, 1, 0)),
+        ("2021-12-15T00:00:00", "2021-12-15T00:00:00", true, (0, 0, 0)),
+        ("9999-12-31T23:59:59", "0000-01-01T00:00:00", true, (9999, 11, 30)),
+    ] {
+        let delta = calendar_diff(a, b);
+        assert_eq!(delta.positive, positive, "{a} - {b}");
+        assert_eq!((delta.years, delta.months, delta.days), ymd, "{a} - {b}");
+    }
+
+    // less than a day
+    assert_eq!(
+        calendar_diff("2022-01-01T12:13:14.5", "2022-01-01T06:00:00.75"),
+        CalendarDelta {
+            positive: true,
+            hours: 6,
+            minutes: 13,
+            seconds: 13,
+            microseconds: 750_000,
+            ..Default::default()
+        }
+    );
+    // the time carries into the previous day
+    assert_eq!(
+        calendar_diff("2022-03-01T01:00:00", "2022-01-31T02:00:00"),
+        CalendarDelta {
+            positive: true,
+            months: 1,
+            hours: 23,
+            ..Default::default()
+        }
+    );
+    // offsets are converted to the same timezone, naive datetimes use the local time
+    assert_eq!(
+        calendar_diff("2022-01-01T12:00:00+02:00", "2022-01-01T12:00:00Z"),
+        CalendarDelta {
+            positive: false,
+            hours: 2,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        calendar_diff("2022-01-01T12:00:00+02:00", "2022-01-01T10:00:00"),
+        CalendarDelta {
+            positive: true,
+            hours: 2,
+            ..Default::default()
+        }
+    );
+}
+
+#[test]
+fn test_time_hour_only() {
+    let config = TimeConfigBuilder::new().allow_hour_only(true).build();
+    for (input, expected) in [
+        ("12", "12:00:00"),
+        ("12Z", "12:00:00Z"),
+        ("12z", "12:00:00Z"),
+        ("00", "00:00:00"),
+        ("12+01:00", "12:00:00+01:00"),
+        ("12-0130", "12:00:00-01:30"),
+        ("12:13", "12:13:00"),
+        ("12:13:14.5", "12:13:14.500000"),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.to_string(), expected, "{input:?}");
+    }
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01T12Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:00:00Z");
+
+    for (input, error) in [
+        ("25", ParseError::OutOfRangeHour),
+        ("24", ParseError::OutOfRangeHour),
+        ("1", ParseError::TooShort),
+        ("123", ParseError::TooShort),
+        ("12x", ParseError::TooShort),
+        ("12+", ParseError::InvalidCharTzHour),
+    ] {
+        let result = Time::parse_bytes_with_config(input.as_bytes(), &config);
+        assert_eq!(result.unwrap_err(), error, "{input:?}");
+    }
+
+    // by default an hour on its own is rejected
+    assert_eq!(Time::parse_str("12").unwrap_err(), ParseError::TooShort);
+    assert_eq!(Time::parse_str("12Z").unwrap_err(), ParseError::TooShort);
+}
+
+#[test]
+fn duration_checked_signed_total_seconds() {
+    for d in [
+        Duration::ZERO,
+        Duration::MAX,
+        Duration::MIN,
+        Duration::parse_str("P1DT2S").unwrap(),
+        Duration::parse_str("-P1DT2S").unwrap(),
+    ] {
+        assert_eq!(d.checked_signed_total_seconds(), Some(d.signed_total_seconds()), "{d}");
+    }
+    assert_eq!(Duration::MAX.checked_signed_total_seconds(), Some(86_399_999_999_999));
+    assert_eq!(Duration::MIN.checked_signed_total_seconds(), Some(-86_399_999_999_999));
+
+    // even out of range values don't overflow
+    let d = Duration {
+        positive: false,
+        day: u32::MAX,
+        second: u32::MAX,
+        microsecond: 0,
+    };
+    assert_eq!(
+        d.checked_signed_total_seconds(),
+        Some(-(u32::MAX as i64 * 86_400 + u32::MAX as i64))
+    );
+}
+
+#[test]
+fn format_hints() {
+    assert_eq!(Date::format_hint(), "%Y-%m-%d");
+    assert_eq!(Time::format_hint(), "%H:%M:%S[.%f][Z|±HH:MM]");
+    assert_eq!(DateTime::format_hint(), "%Y-%m-%dT%H:%M:%S[.%f][Z|±HH:MM]");
+    assert_eq!(Duration::format_hint(), "[-]P[nY][nD][T[nH][nM][n[.f]S]]");
+}
+
+#[test]
+fn time_cmp_wall() {
+    use std::cmp::Ordering;
+
+    let t1 = Time::parse_str("12:13:14Z").unwrap();
+    let t2 = Time::parse_str("12:13:14+02:00").unwrap();
+    assert_eq!(t1.cmp_wall(&t2), Ordering::Equal);
+    assert_eq!(t1.partial_cmp(&t2), Some(Ordering::Greater));
+    assert_ne!(t1, t2);
+
+    let t3 = Time::parse_str("11:13:14-02:00").unwrap();
+    assert_eq!(t1.cmp_wall(&t3), Ordering::Greater);
+    assert_eq!(t1.partial_cmp(&t3), Some(Ordering::Less));
+
+    let t4 = Time::parse_str("12:13:14.000001").unwrap();
+    assert_eq!(t1.cmp_wall(&t4), Ordering::Less);
+    assert_eq!(t4.cmp_wall(&t1), Ordering::Greater);
+    assert_eq!(t4.cmp_wall(&t4), Ordering::Equal);
+}
+
+#[test]
+fn datetime_timestamp_at_prefix() {
+    let config = TimeConfigBuilder::new().allow_timestamp_at_prefix(true).build();
+    for (input, expected) in [
+        ("@1654646400", "2022-06-08T00:00:00"),
+        ("@1654646400.5", "2022-06-08T00:00:00.500000"),
+        ("@-1", "1969-12-31T23:59:59"),
+        ("1654646400", "2022-06-08T00:00:00"),
+        ("2022-06-08T00:00:00", "2022-06-08T00:00:00"),
+    ] {
+        let dt = DateTime::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(dt.to_string(), expected, "{input:?}");
+    }
+    for (input, error) in [
+        ("@", ParseError::TooShort),
+        ("@@1654646400", ParseError::InvalidCharYear),
+        ("@foobar", ParseError::TooShort),
+        ("@2022-06-08T00:00:00", ParseError::InvalidCharYear),
+        ("@1654646400x", ParseError::InvalidCharYear),
+    ] {
+        let result = DateTime::parse_bytes_with_config(input.as_bytes(), &config);
+        assert_eq!(result.unwrap_err(), error, "{input:?}");
+    }
+
+    // by default the `@` prefix is rejected
+    assert_eq!(
+        DateTime::parse_str("@1654646400").unwrap_err(),
+        ParseError::InvalidCharYear
+    );
+}
+
+#[test]
+fn date_from_timestamp_with_remainder() {
+    let config = TimeConfigBuilder::new().build();
+    let (d, t) = Date::from_timestamp_with_remainder(1_654_601_594, &config).unwrap();
+    assert_eq!(d.to_string(), "2022-06-07");
+    assert_eq!(t.to_string(), "11:33:14");
+
+    let (d, t) = Date::from_timestamp_with_remainder(1_654_560_000, &config).unwrap();
+    assert_eq!(d.to_string(), "2022-06-07");
+    assert_eq!(t, Time::default());
+
+    // milliseconds
+    let (d, t) = Date::from_timestamp_with_remainder(1_654_601_594_123, &config).unwrap();
+    assert_eq!(d.to_string(), "2022-06-07");
+    assert_eq!(t.to_string(), "11:33:14.123000");
+
+    // negative timestamps are before midnight on the previous day
+    let (d, t) = Date::from_timestamp_with_remainder(-1, &config).unwrap();
+    assert_eq!(d.to_string(), "1969-12-31");
+    assert_eq!(t.to_string(), "23:59:59");
+
+    let config = TimeConfigBuilder::new().unix_timestamp_offset(Some(0)).build();
+    let (_, t) = Date::from_timestamp_with_remainder(1_654_601_594, &config).unwrap();
+    assert_eq!(t.to_string(), "11:33:14Z");
+
+    assert_eq!(
+        Date::from_timestamp_with_remainder(i64::MAX, &config).unwrap_err(),
+        ParseError::DateTooLarge
+    );
+}
+
+#[test]
+fn time_set_timezone_offset() {
+    let mut t = Time::parse_str("12:13:14").unwrap();
+    assert_eq!(t.as_offset_seconds(), None);
+
+    t.set_timezone_offset(Some(-5 * 3600)).unwrap();
+    assert_eq!(t.as_offset_seconds(), Some(-5 * 3600));
+    assert_eq!(t.to_string(), "12:13:14-05:00");
+
+    t.set_timezone_offset(Some(86_399)).unwrap();
+    assert_eq!(t.as_offset_seconds(), Some(86_399));
+
+    for offset in [86_400, -86_400, i32::MAX, i32::MIN + 1] {
+        assert_eq!(t.set_timezone_offset(Some(offset)), Err(ParseError::OutOfRangeTz));
+        // the time is unchanged
+        assert_eq!(t.as_offset_seconds(), Some(86_399));
+    }
+
+    t.set_timezone_offset(None).unwrap();
+    assert_eq!(t.as_offset_seconds(), None);
+    assert_eq!(t.to_string(), "12:13:14");
+}
+
+#[test]
+fn datetime_parse_bytes_nanos() {
+    let config = TimeConfigBuilder::new().build();
+    for (input, expected, expected_nanosecond) in [
+        ("2022-01-01T12:13:14.123456789", "2022-01-01T12:13:14.123456", 789),
+        ("2022-01-01T12:13:14,123456789Z", "2022-01-01T12:13:14.123456Z", 789),
+        (
+            "2022-01-01T12:13:14.9999999+01:00",
+            "2022-01-01T12:13:14.999999+01:00",
+            900,
+        ),
+        ("2022-01-01T12:13:14.00000001", "2022-01-01T12:13:14", 10),
+        ("2022-01-01T12:13:14.123456", "2022-01-01T12:13:14.123456", 0),
+        ("2022-01-01T12:13:14.5", "2022-01-01T12:13:14.500000", 0),
+        ("2022-01-01T12:13:14Z", "2022-01-01T12:13:14Z", 0),
+    ] {
+        let (dt, nanosecond) = DateTime::parse_bytes_nanos(input.as_bytes(), &config).unwrap();
+        assert_eq!(dt.to_string(), expected, "{input:?}");
+        assert_eq!(nanosecond, expected_nanosecond, "{input:?}");
+    }
+
+    // beyond nanoseconds the overflow behavior applies
+    assert_eq!(
+        DateTime::parse_bytes_nanos(b"2022-01-01T12:13:14.1234567891", &config).unwrap_err(),
+        ParseError::SecondFractionTooLong
+    );
+    let config = TimeConfigBuilder::new()
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Truncate)
+        .build();
+    let (dt, nanosecond) = DateTime::parse_bytes_nanos(b"2022-01-01T12:13:14.1234567891", &config).unwrap();
+    assert_eq!(dt.to_string(), "2022-01-01T12:13:14.123456");
+    assert_eq!(nanosecond, 789);
+
+    assert_eq!(
+        DateTime::parse_bytes_nanos(b"2022-01-01T12:13:14.", &config).unwrap_err(),
+        ParseError::SecondFractionMissing
+    );
+
+    // a fraction of a minute has no nanoseconds
+    let config = TimeConfigBuilder::new()
+        .allow_fractional_minutes(true)
+        .microseconds_precision_overflow_behavior(MicrosecondsPrecisionOverflowBehavior::Truncate)
+        .build();
+    let (dt, nanosecond) = DateTime::parse_bytes_nanos(b"2022-01-01T12:13.123456789Z", &config).unwrap();
+    assert_eq!(
+        dt,
+        DateTime::parse_bytes_with_config(b"2022-01-01T12:13.123456789Z", &config).unwrap()
+    );
+    assert_eq!(nanosecond, 0);
+    let config = TimeConfigBuilder::new().allow_fractional_minutes(true).build();
+    assert_eq!(
+        DateTime::parse_bytes_nanos(b"2022-01-01T12:13.123456789Z", &config).unwrap_err(),
+        ParseError::SecondFractionTooLong
+    );
+
+    let config = TimeConfigBuilder::new().allow_basic_format(true).build();
+    let (dt, nanosecond) = DateTime::parse_bytes_nanos(b"2022-01-01T121314.123456789Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2022-01-01T12:13:14.123456Z");
+    assert_eq!(nanosecond, 789);
+}
+
+#[test]
+fn date_timestamp_range() {
+    for date in ["2022-06-07", "1970-01-01", "1969-12-31", "2020-02-29", "2000-12-31"] {
+        let d = Date::parse_str(date).unwrap();
+        let (start, end) = d.timestamp_range();
+        assert_eq!(start, d.timestamp(), "{date}");
+        assert_eq!(end - start, 86_400, "{date}");
+
+        // the start is on the date, the end is on the next date
+        let first = DateTime::from_timestamp(start, 0).unwrap();
+        assert_eq!(first.to_string(), format!("{date}T00:00:00"));
+        let last = DateTime::from_timestamp(end - 1, 999_999).unwrap();
+        assert_eq!(last.to_string(), format!("{date}T23:59:59.999999"));
+    }
+    let (start, end) = Date::parse_str("2022-06-07").unwrap().timestamp_range();
+    assert_eq!(start, 1_654_560_000);
+    assert_eq!(end, 1_654_646_400);
+    assert_eq!(end, Date::parse_str("2022-06-08").unwrap().timestamp());
+}
+
+#[test]
+fn duration_to_compact_string() {
+    for (input, expected) in [
+        ("P1Y2M3DT4H5M6S", "1y 63d 4h 5m 6s"),
+        ("-P1Y2M3DT4H5M6S", "-1y 63d 4h 5m 6s"),
+        ("PT0S", "0s"),
+        ("P1Y", "1y"),
+        ("P1D", "1d"),
+        ("PT1H1S", "1h 1s"),
+        ("PT0.000001S", "0.000001s"),
+        ("PT1M0.5S", "1m 0.5s"),
+        ("P1DT2H", "1d 2h"),
+    ] {
+        let d = Duration::parse_str(input).unwrap();
+        assert_eq!(d.to_compact_string(), expected, "{input:?}");
+    }
+    assert_eq!(Duration::ZERO.to_compact_string(), "0s");
+    assert_eq!(Duration::MAX.to_compact_string(), "2739726y 9d 23h 59m 59.999999s");
+}
+
+#[test]
+fn test_time_space_padding() {
+    let config = TimeConfigBuilder::new().allow_space_padding(true).build();
+    for (input, expected) in [
+        (" 9:05:03", "09:05:03"),
+        (" 9: 5: 3", "09:05:03"),
+        ("09: 5:03.5Z", "09:05:03.500000Z"),
+        (" 9:05+01:00", "09:05:00+01:00"),
+        ("12:13:14", "12:13:14"),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.to_string(), expected, "{input:?}");
+    }
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01T 9:05:03", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T09:05:03");
+
+    for (input, error) in [
+        ("  9:05:03", ParseError::UnexpectedWhitespace),
+        (" x:05:03", ParseError::UnexpectedWhitespace),
+        ("9 :05:03", ParseError::UnexpectedWhitespace),
+        ("09:05:03 ", ParseError::InvalidCharTzSign),
+    ] {
+        let result = Time::parse_bytes_with_config(input.as_bytes(), &config);
+        assert_eq!(result.unwrap_err(), error, "{input:?}");
+    }
+
+    // by default spaces are rejected
+    assert_eq!(
+        Time::parse_str(" 9:05:03").unwrap_err(),
+        ParseError::UnexpectedWhitespace
+    );
+}
+
+#[test]
+fn date_parse_month_day() {
+    assert_eq!(Date::parse_month_day_bytes(b"--12-25"), Ok((12, 25)));
+    assert_eq!(Date::parse_month_day_bytes(b"--01-01"), Ok((1, 1)));
+    assert_eq!(Date::parse_month_day_bytes(b"--02-29"), Ok((2, 29)));
+    assert_eq!(Date::parse_month_day_bytes(b"--04-30"), Ok((4, 30)));
+
+    for (input, error) in [
+        ("--13-01", ParseError::OutOfRangeMonth),
+        ("--00-01", ParseError::OutOfRangeMonth),
+        ("--02-30", ParseError::OutOfRangeDay),
+        ("--04-31", ParseError::OutOfRangeDay),
+        ("--12-00", ParseError::OutOfRangeDay),
+        ("--12-2", ParseError::TooShort),
+        ("--12-255", ParseError::ExtraCharacters),
+        ("-+12-25", ParseError::InvalidCharDateSep),
+        ("+-12-25", ParseError::InvalidCharDateSep),
+        ("--12/25", ParseError::InvalidCharDateSep),
+        ("--1x-25", ParseError::InvalidCharMonth),
+        ("--12-x5", ParseError::InvalidCharDay),
+        ("2020-12-25", ParseError::ExtraCharacters),
+    ] {
+        assert_eq!(Date::parse_month_day_bytes(input.as_bytes()), Err(error), "{input:?}");
+    }
+}
+
+#[test]
+fn date_parse_year_month() {
+    assert_eq!(Date::parse_year_month_bytes(b"2020-06"), Ok((2020, 6)));
+    assert_eq!(Date::parse_year_month_bytes(b"0000-01"), Ok((0, 1)));
+    assert_eq!(Date::parse_year_month_bytes(b"9999-12"), Ok((9999, 12)));
+
+    for (input, error) in [
+        ("2020-13", ParseError::OutOfRangeMonth),
+        ("2020-00", ParseError::OutOfRangeMonth),
+        ("2020-6", ParseError::TooShort),
+        ("2020", ParseError::TooShort),
+        ("2020-06-01", ParseError::ExtraCharacters),
+        ("2020/06", ParseError::InvalidCharDateSep),
+        ("202x-06", ParseError::InvalidCharYear),
+        ("2020-x6", ParseError::InvalidCharMonth),
+    ] {
+        assert_eq!(Date::parse_year_month_bytes(input.as_bytes()), Err(error), "{input:?}");
+    }
+}
+
+#[test]
+fn max_input_len() {
+    let long_number = "1".repeat(1_000_000);
+    let config = TimeConfigBuilder::new().max_input_len(Some(64)).build();
+    assert_eq!(
+        DateTime::parse_bytes_with_config(long_number.as_bytes(), &config).unwrap_err(),
+        ParseError::InputTooLong
+    );
+    assert_eq!(
+        DateTime::parse_bytes_rfc3339_with_config(long_number.as_bytes(), &config).unwrap_err(),
+        ParseError::InputTooLong
+    );
+    assert_eq!(
+        Time::parse_bytes_with_config(long_number.as_bytes(), &config).unwrap_err(),
+        ParseError::InputTooLong
+    );
+    assert_eq!(
+        Duration::parse_bytes_with_config(long_number.as_bytes(), &config).unwrap_err(),
+        ParseError::InputTooLong
+    );
+    let date_config = DateConfigBuilder::new().max_input_len(Some(64)).build();
+    assert_eq!(
+        Date::parse_bytes_with_config(long_number.as_bytes(), &date_config).unwrap_err(),
+        ParseError::InputTooLong
+    );
+
+    // the limit is inclusive
+    let config = TimeConfigBuilder::new().max_input_len(Some(20)).build();
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01T12:13:14Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"2020-01-01T12:13:14.5Z", &config).unwrap_err(),
+        ParseError::InputTooLong
+    );
+    let date_config = DateConfigBuilder::new().max_input_len(Some(10)).build();
+    let d = Date::parse_bytes_with_config(b"2020-01-01", &date_config).unwrap();
+    assert_eq!(d.to_string(), "2020-01-01");
+
+    // by default there's no limit, the whole input is parsed
+    assert_eq!(
+        DateTime::parse_str(&long_number).unwrap_err(),
+        ParseError::InvalidCharDateSep
+    );
+}
+
+#[test]
+fn date_from_year_ordinal() {
+    assert_eq!(Date::from_year_ordinal(2020, 60).unwrap().to_string(), "2020-02-29");
+    assert_eq!(Date::from_year_ordinal(2021, 60).unwrap().to_string(), "2021-03-01");
+    assert_eq!(Date::from_year_ordinal(2020, 1).unwrap().to_string(), "2020-01-01");
+    assert_eq!(Date::from_year_ordinal(2020, 366).unwrap().to_string(), "2020-12-31");
+    assert_eq!(Date::from_year_ordinal(2021, 365).unwrap().to_string(), "2021-12-31");
+
+    assert_eq!(Date::from_year_ordinal(2021, 366), Err(ParseError::OutOfRangeDay));
+    assert_eq!(Date::from_year_ordinal(2020, 367), Err(ParseError::OutOfRangeDay));
+    assert_eq!(Date::from_year_ordinal(2020, 0), Err(ParseError::OutOfRangeDay));
+
+    // round trip every day of a leap and common year
+    for year in [2000, 1900] {
+        let mut d = Date::parse_str(&format!("{year}-01-01")).unwrap();
+        while d.year == year {
+            assert_eq!(Date::from_year_ordinal(year, d.ordinal_day()).unwrap(), d);
+            d = d.add_days(1).unwrap();
+        }
+    }
+}
+
+#[test]
+fn duration_signed_components() {
+    let config = DurationConfigBuilder::new().allow_signed_components(true).build();
+    for (input, expected) in [
+        ("PT-1H30M", "-PT30M"),
+        ("PT1H-30M", "PT30M"),
+        ("-PT-1H30M", "PT30M"),
+        ("P1DT-1H", "PT23H"),
+        ("P-1D", "-P1D"),
+        ("PT-1.5H", "-PT1H30M"),
+        ("PT1H-1H", "PT0S"),
+        ("PT1H30M", "PT1H30M"),
+        ("PT10H-30M", "PT9H30M"),
+        ("P1Y2M-3D", "P1Y57D"),
+        ("P10D-1D", "P9D"),
+        ("P10DT-10H", "P9DT14H"),
+    ] {
+        let d = Duration::parse_bytes_with_duration_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(d.to_string(), expected, "{input:?}");
+    }
+    for (input, error) in [
+        ("PT-H", ParseError::DurationInvalidNumber),
+        ("PT1H-", ParseError::DurationInvalidNumber),
+        ("PT--1H", ParseError::DurationInvalidNumber),
+    ] {
+        let result = Duration::parse_bytes_with_duration_config(input.as_bytes(), &config);
+        assert_eq!(result, Err(error), "{input:?}");
+    }
+
+    let result = Duration::parse_str("PT-1H30M");
+    assert_eq!(result, Err(ParseError::DurationInvalidNumber));
+}
+
+#[test]
+fn time_fraction_separator() {
+    for (fraction_separator, dot, comma) in [
+        (FractionSep::Either, true, true),
+        (FractionSep::DotOnly, true, false),
+        (FractionSep::CommaOnly, false, true),
+    ] {
+        let config = TimeConfigBuilder::new().fraction_separator(fraction_separator).build();
+        for (input, accepted) in [("12:13:14.5", dot), ("12:13:14,5", comma)] {
+            let result = Time::parse_bytes_with_config(input.as_bytes(), &config);
+            if accepted {
+                assert_eq!(
+                    result.unwrap().to_string(),
+                    "12:13:14.500000",
+                    "{fraction_separator:?} {input:?}"
+                );
+            } else {
+                assert_eq!(
+                    result,
+                    Err(ParseError::InvalidCharTzSign),
+                    "{fraction_separator:?} {input:?}"
+                );
+            }
+        }
+    }
+
+    let config = TimeConfigBuilder::new()
+        .fraction_separator(FractionSep::CommaOnly)
+        .build();
+    let dt = DateTime::parse_bytes_with_config(b"2022-01-01T12:13:14,5Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2022-01-01T12:13:14.500000Z");
+    let result = DateTime::parse_bytes_with_config(b"2022-01-01T12:13:14.5Z", &config);
+    assert_eq!(result, Err(ParseError::InvalidCharTzSign));
+}
+
+#[test]
+fn date_ops() {
+    let d = Date::parse_str("2022-12-31").unwrap();
+    assert_eq!((d.clone() + 1).to_string(), "2023-01-01");
+    assert_eq!((d.clone() + -365).to_string(), "2021-12-31");
+    assert_eq!((d.clone() + 0), d);
+    assert_eq!((d.clone() - 1).to_string(), "2022-12-30");
+    assert_eq!((d.clone() - -1).to_string(), "2023-01-01");
+    assert_eq!((d.clone() - 366).to_string(), "2021-12-30");
+
+    let other = Date::parse_str("2020-02-29").unwrap();
+    assert_eq!(d.clone() - other.clone(), 1036);
+    assert_eq!(other.clone() - d.clone(), -1036);
+    assert_eq!(d.clone() - d.clone(), 0);
+    assert_eq!(other.clone() + (d.clone() - other.clone()), d);
+
+    let first = Date::parse_str("0000-01-01").unwrap();
+    let last = Date::parse_str("9999-12-31").unwrap();
+    assert_eq!(last.clone() - first.clone(), 3_652_424);
+    assert_eq!(first.clone() + 3_652_424, last);
+    assert_eq!(last - 3_652_424, first);
+}
+
+#[test]
+#[should_panic(expected = "date out of range: date_too_large")]
+fn date_add_overflow() {
+    let _ = Date::parse_str("9999-12-31").unwrap() + 1;
+}
+
+#[test]
+#[should_panic(expected = "date out of range: date_too_small")]
+fn date_sub_overflow() {
+    let _ = Date::parse_str("0000-01-01").unwrap() - 1;
+}
+
+#[test]
+#[should_panic(expected = "date out of range: date_too_large")]
+fn date_sub_min_overflow() {
+    let _ = Date::parse_str("0000-01-01").unwrap() - i64::MIN;
+}
+
+#[test]
+fn datetime_duration_ops() {
+    let dt = DateTime::parse_str("2022-01-01T12:13:14Z").unwrap();
+    let d = Duration::parse_str("P1DT1.5S").unwrap();
+    assert_eq!((dt.clone() + &d).to_string(), "2022-01-02T12:13:15.500000Z");
+    assert_eq!(dt.clone() + &d, dt.add_duration(&d).unwrap());
+    assert_eq!((dt.clone() - &d).to_string(), "2021-12-31T12:13:12.500000Z");
+    assert_eq!(dt.clone() - &d, dt.sub_duration(&d).unwrap());
+
+    let d = Duration::parse_str("-PT13H").unwrap();
+    assert_eq!((dt.clone() + &d).to_string(), "2021-12-31T23:13:14Z");
+    assert_eq!((dt.clone() - &d).to_string(), "2022-01-02T01:13:14Z");
+    assert_eq!(dt.clone() + &Duration::ZERO, dt);
+
+    let dt2 = DateTime::parse_str("2021-12-31T23:13:14.5Z").unwrap();
+    assert_eq!((&dt - &dt2).to_string(), "PT12H59M59.5S");
+    assert_eq!((&dt2 - &dt).to_string(), "-PT12H59M59.5S");
+    assert_eq!(&dt - &dt, Duration::ZERO);
+    assert_eq!(dt2.clone() + &(&dt - &dt2), dt);
+
+    // same instant with different offsets
+    let dt3 = DateTime::parse_str("2022-01-01T13:13:14+01:00").unwrap();
+    assert_eq!(&dt3 - &dt, Duration::ZERO);
+    // naive datetimes use the local time
+    let naive = DateTime::parse_str("2022-01-01T13:13:14").unwrap();
+    assert_eq!((&naive - &dt).to_string(), "PT1H");
+
+    let diff = &DateTime::MAX - &DateTime::MIN;
+    assert_eq!(diff.to_string(), "P10006Y234DT23H59M59.999999S");
+    assert_eq!(DateTime::MIN + &diff, DateTime::MAX);
+}
+
+#[test]
+#[should_panic(expected = "datetime out of range: date_too_large")]
+fn datetime_add_duration_overflow() {
+    let _ = DateTime::MAX + &Duration::parse_str("PT1S").unwrap();
+}
+
+#[test]
+#[should_panic(expected = "datetime out of range: date_too_small")]
+fn datetime_sub_duration_overflow() {
+    let _ = DateTime::MIN - &Duration::parse_str("PT1S").unwrap();
+}
+
+#[test]
+fn datetime_eq_instant() {
+    for (a, b) in [
+        ("2000-01-01T15:00:00Z", "2000-01-01T16:00:00+01:00"),
+        ("2000-01-01T15:00:00Z", "2000-01-01T15:00:00+00:00"),
+        ("2000-01-01T00:30:00.123456+01:00", "1999-12-31T18:00:00.123456-05:30"),
+        ("2000-01-01T15:00:00", "2000-01-01T15:00:00Z"),
+        ("2000-01-01T15:00:00", "2000-01-01T15:00:00"),
+    ] {
+        let dt_a = DateTime::parse_str(a).unwrap();
+        let dt_b = DateTime::parse_str(b).unwrap();
+        assert!(dt_a.eq_instant(&dt_b), "{a} {b}");
+        assert!(dt_b.eq_instant(&dt_a), "{a} {b}");
+    }
+    assert_ne!(
+        DateTime::parse_str("2000-01-01T15:00:00Z").unwrap(),
+        DateTime::parse_str("2000-01-01T16:00:00+01:00").unwrap()
+    );
+
+    for (a, b) in [
+        ("2000-01-01T15:00:00Z", "2000-01-01T15:00:00+01:00"),
+        ("2000-01-01T15:00:00Z", "2000-01-01T15:00:00.000001Z"),
+        ("2000-01-01T15:00:00Z", "2000-01-02T15:00:00Z"),
+        ("2000-01-01T16:00:00", "2000-01-01T16:00:00+01:00"),
+    ] {
+        let dt_a = DateTime::parse_str(a).unwrap();
+        let dt_b = DateTime::parse_str(b).unwrap();
+        assert!(!dt_a.eq_instant(&dt_b), "{a} {b}");
+        assert!(!dt_b.eq_instant(&dt_a), "{a} {b}");
+    }
+}
+
+#[test]
+fn time_eq_instant() {
+    let t = Time::parse_str("12:00:00.5Z").unwrap();
+    assert!(t.eq_instant(&Time::parse_str("13:00:00.5+01:00").unwrap()));
+    assert!(t.eq_instant(&Time::parse_str("12:00:00.5").unwrap()));
+    assert!(!t.eq_instant(&Time::parse_str("12:00:00.5+01:00").unwrap()));
+    assert!(!t.eq_instant(&Time::parse_str("12:00:00Z").unwrap()));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn datetime_parse_reader() {
+    for input in [
+        "2022-01-01T12:13:14Z",
+        "2022-01-01T12:13:14.123456+01:00",
+        "9999-12-31T23:59:59.999999\u{2212}23:59",
+        "1641039194",
+    ] {
+        let dt = DateTime::parse_reader(&mut Cursor::new(input.as_bytes())).unwrap();
+        assert_eq!(dt, DateTime::parse_str(input).unwrap(), "{input:?}");
+    }
+
+    for (input, error) in [
+        ("", ParseError::TooShort),
+        ("2022-01-01T12:1", ParseError::TooShort),
+        ("2022-01-01T12:13:14Zx", ParseError::ExtraCharacters),
+        ("2022-01-01T12:13:14Z\n2022-01-02T12:13:14Z", ParseError::InputTooLong),
+    ] {
+        let result = DateTime::parse_reader(&mut Cursor::new(input.as_bytes()));
+        assert_eq!(result, Err(error), "{input:?}");
+    }
+
+    // only one byte more than the longest valid input is read
+    let mut reader = Cursor::new(b"2022-01-01T12:13:14.123456+01:00:00 more");
+    assert_eq!(DateTime::parse_reader(&mut reader), Err(ParseError::InputTooLong));
+    assert_eq!(reader.position(), 35);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn datetime_parse_reader_error() {
+    struct FailingReader;
+
+    impl std::io::Read for FailingReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            Err(std::io::Error::other("broken"))
+        }
+    }
+
+    assert_eq!(DateTime::parse_reader(&mut FailingReader), Err(ParseError::ReadError));
+    assert_eq!(Date::parse_reader(&mut FailingReader), Err(ParseError::ReadError));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn date_parse_reader() {
+    for (input, expected) in [
+        ("2022-01-01", "2022-01-01"),
+        ("1640995200", "2022-01-01"),
+        ("-62167219200000", "0000-01-01"),
+    ] {
+        let d = Date::parse_reader(&mut Cursor::new(input.as_bytes())).unwrap();
+        assert_eq!(d.to_string(), expected, "{input:?}");
+    }
+
+    for (input, error) in [
+        ("2022-01", ParseError::TooShort),
+        ("2022-01-01T", ParseError::ExtraCharacters),
+        ("2022-01-01 2022-01-02", ParseError::InputTooLong),
+    ] {
+        let result = Date::parse_reader(&mut Cursor::new(input.as_bytes()));
+        assert_eq!(result, Err(error), "{input:?}");
+    }
+}
+
+#[test]
+fn time_to_string_trimmed() {
+    for (input, expected) in [
+        ("12:13:14.500000", "12:13:14.5"),
+        ("12:13:14.123000", "12:13:14.123"),
+        ("12:13:14.123456", "12:13:14.123456"),
+        ("12:13:14.000001", "12:13:14.000001"),
+        ("12:13:14.050000Z", "12:13:14.05Z"),
+        ("12:13:14.000000", "12:13:14"),
+        ("12:13:14.100-02:30", "12:13:14.1-02:30"),
+        ("12:13", "12:13:00"),
+    ] {
+        let t = Time::parse_str(input).unwrap();
+        assert_eq!(t.to_string_trimmed(), expected, "{input:?}");
+        assert_eq!(Time::parse_str(expected).unwrap(), t, "{input:?}");
+    }
+
+    let dt = DateTime::parse_str("2022-01-01T12:13:14.500000Z").unwrap();
+    assert_eq!(dt.to_string_trimmed(), "2022-01-01T12:13:14.5Z");
+    let dt = DateTime::parse_str("2022-01-01T12:13:14.000000").unwrap();
+    assert_eq!(dt.to_string_trimmed(), "2022-01-01T12:13:14");
+}
+
+#[test]
+fn relative_keywords() {
+    for input in ["now", "today", "yesterday", "tomorrow", "NOW", "Today"] {
+        assert_eq!(
+            Date::parse_str(input),
+            Err(ParseError::RelativeNotSupported),
+            "{input:?}"
+        );
+        assert_eq!(
+            DateTime::parse_str(input),
+            Err(ParseError::RelativeNotSupported),
+            "{input:?}"
+        );
+        assert_eq!(
+            Duration::parse_str(input),
+            Err(ParseError::RelativeNotSupported),
+            "{input:?}"
+        );
+    }
+    let error = Date::parse_str("yesterday").unwrap_err();
+    assert_eq!(error.to_string(), "relative_not_supported");
+
+    let config = TimeConfigBuilder::new().trim_whitespace(true).build();
+    let result = DateTime::parse_bytes_with_config(b" now\n", &config);
+    assert_eq!(result, Err(ParseError::RelativeNotSupported));
+
+    // only whole keywords are detected
+    for input in ["nowhere", "today!", "no"] {
+        assert_ne!(
+            Date::parse_str(input),
+            Err(ParseError::RelativeNotSupported),
+            "{input:?}"
+        );
+        assert_ne!(
+            DateTime::parse_str(input),
+            Err(ParseError::RelativeNotSupported),
+            "{input:?}"
+        );
+        assert_ne!(
+            Duration::parse_str(input),
+            Err(ParseError::RelativeNotSupported),
+            "{input:?}"
+        );
+    }
+    assert_eq!(Duration::parse_str("-now"), Err(ParseError::RelativeNotSupported));
+}
+
+#[test]
+fn time_decimal_tz_offset() {
+    let config = TimeConfigBuilder::new().allow_decimal_tz_offset(true).build();
+    for (input, expected) in [
+        ("12:13:14+5.5", 19_800),
+        ("12:13:14+5.75", 20_700),
+        ("12:13:14-3.5", -12_600),
+        ("12:13:14+05.25", 18_900),
+        ("12:13:14+5.333333333333", 19_200),
+        ("12:13:14+0.0", 0),
+        ("12:13:14+23.99", 86_364),
+        ("12:13:14+05:30", 19_800),
+        ("12:13:14\u{2212}1.5", -5_400),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.tz_offset, Some(expected), "{input:?}");
+    }
+    for (input, error) in [
+        ("12:13:14+24.0", ParseError::OutOfRangeTz),
+        ("12:13:14+5.", ParseError::InvalidCharTzMinute),
+        ("12:13:14+5.5x", ParseError::ExtraCharacters),
+        ("12:13:14+.5", ParseError::InvalidCharTzHour),
+        ("12:13:14+123.5", ParseError::InvalidCharTzMinute),
+    ] {
+        let result = Time::parse_bytes_with_config(input.as_bytes(), &config);
+        assert_eq!(result, Err(error), "{input:?}");
+    }
+
+    let dt = DateTime::parse_bytes_with_config(b"2022-01-01T12:13:14+5.5", &config).unwrap();
+    assert_eq!(dt.to_string(), "2022-01-01T12:13:14+05:30");
+
+    assert_eq!(Time::parse_str("12:13:14+5.5"), Err(ParseError::InvalidCharTzHour));
+    assert_eq!(Time::parse_str("12:13:14+05.5"), Err(ParseError::InvalidCharTzMinute));
+}
+
+#[test]
+fn date_julian_day_number() {
+    for (input, jdn) in [
+        ("1970-01-01", 2_440_588),
+        ("2000-01-01", 2_451_545),
+        ("1858-11-17", 2_400_001),
+        ("1582-10-15", 2_299_161),
+        ("1969-12-31", 2_440_587),
+        ("0000-01-01", 1_721_060),
+        ("9999-12-31", 5_373_484),
+    ] {
+        let d = Date::parse_str(input).unwrap();
+        assert_eq!(d.julian_day_number(), jdn, "{input:?}");
+        assert_eq!(Date::from_julian_day_number(jdn).unwrap(), d, "{input:?}");
+    }
+
+    for jdn in (1_721_060..=5_373_484).step_by(997) {
+        let d = Date::from_julian_day_number(jdn).unwrap();
+        assert_eq!(d.julian_day_number(), jdn);
+    }
+
+    assert_eq!(Date::from_julian_day_number(1_721_059), Err(ParseError::DateTooSmall));
+    assert_eq!(Date::from_julian_day_number(5_373_485), Err(ParseError::DateTooLarge));
+    assert_eq!(Date::from_julian_day_number(i64::MIN), Err(ParseError::DateTooSmall));
+    assert_eq!(Date::from_julian_day_number(i64::MAX), Err(ParseError::DateTooLarge));
+}
+
+#[test]
+fn datetime_to_mjd() {
+    for (input, mjd) in [
+        ("1858-11-17T00:00:00", 0.0),
+        ("1970-01-01T00:00:00", 40_587.0),
+        ("2000-01-01T12:00:00", 51_544.5),
+        ("2022-06-07T06:00:00Z", 59_737.25),
+        ("2022-06-07T06:00:00+02:00", 59_737.25),
+        ("2024-03-26T09:26:15.471865", 60_395.393_234_628_06),
+        ("1800-01-01T18:00:00", -21_503.25),
+        ("0000-01-01T00:00:00", -678_941.0),
+        ("9999-12-31T23:59:59.999999", 2_973_484.0),
+    ] {
+        let dt = DateTime::parse_str(input).unwrap();
+        let diff = (dt.to_mjd() - mjd).abs();
+        // within 50 microseconds, in days
+        assert!(diff < 50e-6 / 86_400.0, "{input:?} {} != {mjd}", dt.to_mjd());
+    }
+
+    // microseconds are distinguishable for current dates
+    let dt1 = DateTime::parse_str("2024-01-01T00:00:00.000001").unwrap();
+    let dt2 = DateTime::parse_str("2024-01-01T00:00:00.000002").unwrap();
+    assert!(dt2.to_mjd() > dt1.to_mjd());
+}
+
+#[test]
+fn datetime_timestamp_unit_infer_strict() {
+    let config = TimeConfigBuilder::new()
+        .timestamp_unit(TimestampUnit::InferStrict)
+        .build();
+    for (timestamp, expected) in [
+        (20_000_000_000, Ok("2603-10-11T11:33:20")),
+        (-20_000_000_000, Ok("1336-03-23T12:26:40")),
+        (20_000_000_001, Err(ParseError::AmbiguousTimestamp)),
+        (-20_000_000_001, Err(ParseError::AmbiguousTimestamp)),
+        // valid in seconds, but above the watershed
+        (253_402_300_799, Err(ParseError::AmbiguousTimestamp)),
+        // out of range in seconds, but only 12 digits
+        (999_999_999_999, Err(ParseError::AmbiguousTimestamp)),
+        (1_000_000_000_000, Ok("2001-09-09T01:46:40")),
+        (-1_000_000_000_000, Ok("1938-04-24T22:13:20")),
+        (1_577_880_794_123, Ok("2020-01-01T12:13:14.123000")),
+    ] {
+        let result = DateTime::from_timestamp_with_config(timestamp, 0, &config).map(|dt| dt.to_string());
+        assert_eq!(result.as_deref().map_err(Clone::clone), expected, "{timestamp}");
+    }
+    // by default the watershed decides
+    let dt = DateTime::from_timestamp(20_000_000_001, 0).unwrap();
+    assert_eq!(dt.to_string(), "1970-08-20T11:33:20.001000");
+    let dt = DateTime::from_timestamp(999_999_999_999, 0).unwrap();
+    assert_eq!(dt.to_string(), "2001-09-09T01:46:39.999000");
+
+    for (input, expected) in [
+        ("20000000000", Ok("2603-10-11T11:33:20")),
+        ("20000000001", Err(ParseError::AmbiguousTimestamp)),
+        ("20000000001.5", Err(ParseError::AmbiguousTimestamp)),
+        ("1577880794123", Ok("2020-01-01T12:13:14.123000")),
+        ("1577880794123.5", Ok("2020-01-01T12:13:14.123500")),
+    ] {
+        let result = DateTime::parse_bytes_with_config(input.as_bytes(), &config).map(|dt| dt.to_string());
+        assert_eq!(result.as_deref().map_err(Clone::clone), expected, "{input:?}");
+    }
+}
+
+#[test]
+fn datetime_from_cocoa_timestamp() {
+    for (timestamp, expected) in [
+        (0.0, "2001-01-01T00:00:00"),
+        (662_688_000.0, "2022-01-01T00:00:00"),
+        (1.5, "2001-01-01T00:00:01.500000"),
+        (-0.25, "2000-12-31T23:59:59.750000"),
+        (-978_307_200.0, "1970-01-01T00:00:00"),
+        (0.9999999, "2001-01-01T00:00:01"),
+    ] {
+        let dt = DateTime::from_cocoa_timestamp(timestamp).unwrap();
+        assert_eq!(dt.to_string(), expected, "{timestamp}");
+    }
+    assert_eq!(DateTime::from_cocoa_timestamp(1e12), Err(ParseError::DateTooLarge));
+    assert_eq!(DateTime::from_cocoa_timestamp(-1e12), Err(ParseError::DateTooSmall));
+    assert_eq!(
+        DateTime::from_cocoa_timestamp(f64::INFINITY),
+        Err(ParseError::DateTooLarge)
+    );
+    assert_eq!(
+        DateTime::from_cocoa_timestamp(f64::NEG_INFINITY),
+        Err(ParseError::DateTooSmall)
+    );
+    assert_eq!(DateTime::from_cocoa_timestamp(f64::NAN), Err(ParseError::DateTooLarge));
+}
+
+#[test]
+fn datetime_from_dotnet_ticks() {
+    for (ticks, expected) in [
+        (0, "0001-01-01T00:00:00"),
+        (621_355_968_000_000_000, "1970-01-01T00:00:00"),
+        (637_134_336_000_000_000, "2020-01-01T00:00:00"),
+        (637_134_336_001_234_567, "2020-01-01T00:00:00.123456"),
+        (3_155_378_975_999_999_999, "9999-12-31T23:59:59.999999"),
+        (-10, "0000-12-31T23:59:59.999999"),
+    ] {
+        let dt = DateTime::from_dotnet_ticks(ticks).unwrap();
+        assert_eq!(dt.to_string(), expected, "{ticks}");
+    }
+    assert_eq!(DateTime::from_dotnet_ticks(i64::MAX), Err(ParseError::DateTooLarge));
+    assert_eq!(DateTime::from_dotnet_ticks(i64::MIN), Err(ParseError::DateTooSmall));
+}
+
+#[test]
+fn datetime_parse_bytes_ctx() {
+    let error = DateTime::parse_bytes_ctx(b"2022-01-01T12:6x:14Z").unwrap_err();
+    assert_eq!(
+        error,
+        ParseErrorWithInput {
+            error: ParseError::InvalidCharMinute,
+            offset: 14,
+            input: b"6x",
+        }
+    );
+    assert_eq!(error.to_string(), "invalid_char_minute at byte 14: \"6x\"");
+
+    for (input, error, offset, context) in [
+        ("2022-01-01T12:61:14Z", ParseError::OutOfRangeMinute, 14, "61"),
+        ("202x-01-01T12:13:14Z", ParseError::InvalidCharYear, 0, "202x"),
+        ("2022/01-01T12:13:14Z", ParseError::InvalidCharDateSep, 4, "/"),
+        ("2022-01/01T12:13:14Z", ParseError::InvalidCharDateSep, 7, "/"),
+        ("2022-13-01T12:13:14Z", ParseError::OutOfRangeMonth, 5, "13"),
+        ("2022-02-30T12:13:14Z", ParseError::OutOfRangeDay, 8, "30"),
+        ("2022-01-01T25:13:14Z", ParseError::OutOfRangeHour, 11, "25"),
+        ("2022-01-01T12:13:1x", ParseError::InvalidCharSecond, 17, "1x"),
+        ("2022-01-01T12:13:14.", ParseError::SecondFractionMissing, 19, "."),
+        (
+            "2022-01-01T12:13:14.1234567Z",
+            ParseError::SecondFractionTooLong,
+            19,
+            ".1234567",
+        ),
+        ("2022-01-01T12:13:14.123+25:00", ParseError::OutOfRangeTz, 23, "+25:00"),
+        ("2022-01-01T12:13:14x", ParseError::InvalidCharTzSign, 19, "x"),
+        (
+            "2022-01-01T12:13:14Z+01:00",
+            ParseError::DuplicateTimezone,
+            19,
+            "Z+01:00",
+        ),
+        ("2022-01", ParseError::TooShort, 0, "2022-01"),
+        // seconds are optional, so the timezone may start straight after the minutes
+        ("2020-01-01T12:13Z+", ParseError::DuplicateTimezone, 16, "Z+"),
+        ("2020-01-01T12:13+25:00", ParseError::OutOfRangeTz, 16, "+25:00"),
+        ("2020-01-01T12:13:14.5x", ParseError::InvalidCharTzSign, 21, "x"),
+        // unix timestamps aren't accepted
+        ("16546464x0", ParseError::InvalidCharDateSep, 4, "6"),
+        ("1654646400", ParseError::InvalidCharDateSep, 4, "6"),
+    ] {
+        let e = DateTime::parse_bytes_ctx(input.as_bytes()).unwrap_err();
+        assert_eq!(e.error, error, "{input:?}");
+        assert_eq!(e.offset, offset, "{input:?}");
+        assert_eq!(e.input, context.as_bytes(), "{input:?}");
+    }
+
+    let dt = DateTime::parse_bytes_ctx(b"2022-01-01T12:13:14Z").unwrap();
+    assert_eq!(dt.to_string(), "2022-01-01T12:13:14Z");
+}
+
+#[test]
+fn duration_zero_shorthand() {
+    let config = DurationConfigBuilder::new().allow_zero_duration_shorthand(true).build();
+    for input in ["P0D", "PT0S", "0", "P0", "+0", "-0", "P0Y", "PT0M"] {
+        let d = Duration::parse_bytes_with_duration_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(d.signed_total_seconds(), 0, "{input:?}");
+        assert_eq!(d.microsecond, 0, "{input:?}");
+        assert_eq!(d.to_string().trim_start_matches('-'), "PT0S", "{input:?}");
+    }
+    assert_eq!(
+        Duration::parse_bytes_with_duration_config(b"0", &config).unwrap(),
+        Duration::parse_str("PT0S").unwrap()
+    );
+
+    // without the flag only the full forms are accepted
+    assert_eq!(Duration::parse_str("P0D").unwrap(), Duration::ZERO);
+    assert_eq!(Duration::parse_str("PT0S").unwrap(), Duration::ZERO);
+    assert!(Duration::parse_str("0").is_err());
+    assert_eq!(Duration::parse_str("P0"), Err(ParseError::DurationInvalidDateUnit));
+
+    for input in ["00", "P00", "0S", "P0T"] {
+        assert!(
+            Duration::parse_bytes_with_duration_config(input.as_bytes(), &config).is_err(),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn time_with_fields() {
+    let t = Time::parse_str("12:13:14.123456+01:00").unwrap();
+    assert_eq!(t.with_hour(0).unwrap().to_string(), "00:13:14.123456+01:00");
+    assert_eq!(t.with_hour(23).unwrap().to_string(), "23:13:14.123456+01:00");
+    assert_eq!(t.with_minute(59).unwrap().to_string(), "12:59:14.123456+01:00");
+    assert_eq!(t.with_second(0).unwrap().to_string(), "12:13:00.123456+01:00");
+    assert_eq!(
+        t.with_microsecond(999_999).unwrap().to_string(),
+        "12:13:14.999999+01:00"
+    );
+    assert_eq!(t.with_microsecond(0).unwrap().to_string(), "12:13:14+01:00");
+
+    assert_eq!(t.with_hour(24), Err(ParseError::OutOfRangeHour));
+    assert_eq!(t.with_minute(60), Err(ParseError::OutOfRangeMinute));
+    assert_eq!(t.with_second(60), Err(ParseError::OutOfRangeSecond));
+    assert_eq!(t.with_microsecond(1_000_000), Err(ParseError::SecondFractionTooLong));
+    // the original is unchanged
+    assert_eq!(t.to_string(), "12:13:14.123456+01:00");
+}
+
+#[test]
+fn datetime_with_fields() {
+    let dt = DateTime::parse_str("2022-01-01T12:13:14.123456Z").unwrap();
+    assert_eq!(dt.with_hour(1).unwrap().to_string(), "2022-01-01T01:13:14.123456Z");
+    assert_eq!(dt.with_minute(2).unwrap().to_string(), "2022-01-01T12:02:14.123456Z");
+    assert_eq!(dt.with_second(3).unwrap().to_string(), "2022-01-01T12:13:03.123456Z");
+    assert_eq!(
+        dt.with_microsecond(4).unwrap().to_string(),
+        "2022-01-01T12:13:14.000004Z"
+    );
+    let truncated = dt.with_second(0).and_then(|dt| dt.with_microsecond(0)).unwrap();
+    assert_eq!(truncated.to_string(), "2022-01-01T12:13:00Z");
+
+    assert_eq!(dt.with_hour(24), Err(ParseError::OutOfRangeHour));
+    assert_eq!(dt.with_minute(60), Err(ParseError::OutOfRangeMinute));
+    assert_eq!(dt.with_second(60), Err(ParseError::OutOfRangeSecond));
+    assert_eq!(dt.with_microsecond(1_000_000), Err(ParseError::SecondFractionTooLong));
+}
+
+#[test]
+fn date_with_fields() {
+    let d = Date::parse_str("2020-01-31").unwrap();
+    assert_eq!(d.with_year(1999).unwrap().to_string(), "1999-01-31");
+    assert_eq!(d.with_month(12).unwrap().to_string(), "2020-12-31");
+    assert_eq!(d.with_day(1).unwrap().to_string(), "2020-01-01");
+    assert_eq!(d.with_month(2), Err(ParseError::OutOfRangeDay));
+    assert_eq!(d.with_month(4), Err(ParseError::OutOfRangeDay));
+    assert_eq!(d.with_month(0), Err(ParseError::OutOfRangeMonth));
+    assert_eq!(d.with_month(13), Err(ParseError::OutOfRangeMonth));
+    assert_eq!(d.with_day(0), Err(ParseError::OutOfRangeDay));
+    assert_eq!(d.with_day(32), Err(ParseError::OutOfRangeDay));
+    assert_eq!(d.with_year(10_000), Err(ParseError::DateTooLarge));
+    assert_eq!(d.with_year(0).unwrap().to_string(), "0000-01-31");
+
+    // Feb 29 depends on the year
+    let leap = Date::parse_str("2020-02-29").unwrap();
+    assert_eq!(leap.with_year(2021), Err(ParseError::OutOfRangeDay));
+    assert_eq!(leap.with_year(1900), Err(ParseError::OutOfRangeDay));
+    assert_eq!(leap.with_year(2000).unwrap().to_string(), "2000-02-29");
+    let common = Date::parse_str("2021-02-01").unwrap();
+    assert_eq!(common.with_day(29), Err(ParseError::OutOfRangeDay));
+    assert_eq!(
+        common.with_year(2024).unwrap().with_day(29).unwrap().to_string(),
+        "2024-02-29"
+    );
+}
+
+#[test]
+fn duration_iso_corpus() {
+    // expected output with the default config and with `allow_signed_components`
+    let signed_config = DurationConfigBuilder::new().allow_signed_components(true).build();
+    let corpus = [
+        ("P1Y", Ok("P1Y"), Ok("P1Y")),
+        ("PT30M", Ok("PT30M"), Ok("PT30M")),
+        ("P1Y2M3DT4H5M6S", Ok("P1Y63DT4H5M6S"), Ok("P1Y63DT4H5M6S")),
+        ("P1W", Ok("P7D"), Ok("P7D")),
+        ("P1D", Ok("P1D"), Ok("P1D")),
+        ("PT1H", Ok("PT1H"), Ok("PT1H")),
+        ("PT1S", Ok("PT1S"), Ok("PT1S")),
+        ("PT0S", Ok("PT0S"), Ok("PT0S")),
+        ("P0D", Ok("PT0S"), Ok("PT0S")),
+        ("PT1.5S", Ok("PT1.5S"), Ok("PT1.5S")),
+        ("PT0.000001S", Ok("PT0.000001S"), Ok("PT0.000001S")),
+        ("P1.5Y", Ok("P1Y182DT12H"), Ok("P1Y182DT12H")),
+        ("P0.5M", Ok("P15D"), Ok("P15D")),
+        ("P1DT1.25H", Ok("P1DT1H15M"), Ok("P1DT1H15M")),
+        ("PT1,5M", Ok("PT1M30S"), Ok("PT1M30S")),
+        ("P1Y2.5M", Ok("P1Y75D"), Ok("P1Y75D")),
+        (
+            "P1.5YT1H",
+            Err(ParseError::DurationInvalidFraction),
+            Err(ParseError::DurationInvalidFraction),
+        ),
+        (
+            "PT1.5H1M",
+            Err(ParseError::DurationInvalidFraction),
+            Err(ParseError::DurationInvalidFraction),
+        ),
+        ("P999999999D", Ok("P2739726Y9D"), Ok("P2739726Y9D")),
+        (
+            "P1000000000D",
+            Err(ParseError::DurationDaysTooLarge),
+            Err(ParseError::DurationDaysTooLarge),
+        ),
+        ("PT4294967295S", Ok("P136Y70DT6H28M15S"), Ok("P136Y70DT6H28M15S")),
+        (
+            "PT4294967296S",
+            Err(ParseError::DurationValueTooLarge),
+            Err(ParseError::DurationValueTooLarge),
+        ),
+        (
+            "P11759301Y",
+            Err(ParseError::DurationDaysTooLarge),
+            Err(ParseError::DurationDaysTooLarge),
+        ),
+        ("-P1D", Ok("-P1D"), Ok("-P1D")),
+        ("+PT1H", Ok("PT1H"), Ok("PT1H")),
+        ("P", Err(ParseError::TooShort), Err(ParseError::TooShort)),
+        ("PT", Err(ParseError::TooShort), Err(ParseError::TooShort)),
+        (
+            "P1",
+            Err(ParseError::DurationInvalidDateUnit),
+            Err(ParseError::DurationInvalidDateUnit),
+        ),
+        (
+            "PT1",
+            Err(ParseError::DurationInvalidTimeUnit),
+            Err(ParseError::DurationInvalidTimeUnit),
+        ),
+        (
+            "P1X",
+            Err(ParseError::DurationInvalidDateUnit),
+            Err(ParseError::DurationInvalidDateUnit),
+        ),
+        (
+            "PT1X",
+            Err(ParseError::DurationInvalidTimeUnit),
+            Err(ParseError::DurationInvalidTimeUnit),
+        ),
+        (
+            "P1H",
+            Err(ParseError::DurationTimeUnitBeforeT),
+            Err(ParseError::DurationTimeUnitBeforeT),
+        ),
+        (
+            "P1S",
+            Err(ParseError::DurationTimeUnitBeforeT),
+            Err(ParseError::DurationTimeUnitBeforeT),
+        ),
+        (
+            "PT1W",
+            Err(ParseError::DurationWeekAfterT),
+            Err(ParseError::DurationWeekAfterT),
+        ),
+        ("P1YT", Ok("P1Y"), Ok("P1Y")),
+        (
+            "PTT1H",
+            Err(ParseError::DurationTRepeated),
+            Err(ParseError::DurationTRepeated),
+        ),
+        (
+            "PT1HT1M",
+            Err(ParseError::DurationTRepeated),
+            Err(ParseError::DurationTRepeated),
+        ),
+        ("P1Y1Y", Ok("P2Y"), Ok("P2Y")),
+        ("PT1M1H", Ok("PT1H1M"), Ok("PT1H1M")),
+        (
+            "P1.5.5Y",
+            Err(ParseError::DurationInvalidDateUnit),
+            Err(ParseError::DurationInvalidDateUnit),
+        ),
+        (
+            "PT.5S",
+            Err(ParseError::DurationInvalidNumber),
+            Err(ParseError::DurationInvalidNumber),
+        ),
+        ("P-1D", Err(ParseError::DurationInvalidNumber), Ok("-P1D")),
+        ("PT-1H30M", Err(ParseError::DurationInvalidNumber), Ok("-PT30M")),
+        ("P1DT-1H", Err(ParseError::DurationInvalidNumber), Ok("PT23H")),
+        ("P00001D", Ok("P1D"), Ok("P1D")),
+        ("PT86400S", Ok("P1D"), Ok("P1D")),
+        ("PT90061S", Ok("P1DT1H1M1S"), Ok("P1DT1H1M1S")),
+        ("P1YT1.000001S", Ok("P1YT1.000001S"), Ok("P1YT1.000001S")),
+        ("PT0.9999999S", Ok("PT1S"), Ok("PT1S")),
+        ("P0.99999999999999Y", Ok("P1Y"), Ok("P1Y")),
+        ("P1M1W1D", Ok("P38D"), Ok("P38D")),
+        ("P10W", Ok("P70D"), Ok("P70D")),
+        ("PT100000H", Ok("P11Y151DT16H"), Ok("P11Y151DT16H")),
+        (
+            "p1d",
+            Err(ParseError::DurationInvalidNumber),
+            Err(ParseError::DurationInvalidNumber),
+        ),
+        (
+            "Pt1H",
+            Err(ParseError::DurationInvalidNumber),
+            Err(ParseError::DurationInvalidNumber),
+        ),
+        (
+            "P1d",
+            Err(ParseError::DurationInvalidDateUnit),
+            Err(ParseError::DurationInvalidDateUnit),
+        ),
+        (
+            "PT1h",
+            Err(ParseError::DurationInvalidTimeUnit),
+            Err(ParseError::DurationInvalidTimeUnit),
+        ),
+    ];
+    for (input, expected, expected_signed) in corpus {
+        let result = Duration::parse_str(input).map(|d| d.to_string());
+        assert_eq!(result.as_deref(), expected.as_deref(), "{input:?}");
+        let result =
+            Duration::parse_bytes_with_duration_config(input.as_bytes(), &signed_config).map(|d| d.to_string());
+        assert_eq!(result.as_deref(), expected_signed.as_deref(), "{input:?}");
+    }
+}
+
+#[test]
+fn parse_error_codes() {
+    let mut count = 0;
+    for code in 0..1000 {
+        if let Some(error) = ParseError::from_code(code) {
+            assert_eq!(error.code(), code, "{error:?}");
+            count += 1;
+        }
+    }
+    // every variant has a code
+    assert_eq!(count, 58);
+    assert_eq!(ParseError::from_code(0), None);
+    assert_eq!(ParseError::from_code(u32::MAX), None);
+
+    // codes are stable, these must never change
+    for (error, code) in [
+        (ParseError::TooShort, 1),
+        (ParseError::ExtraCharacters, 3),
+        (ParseError::InvalidCharMinute, 13),
+        (ParseError::OutOfRangeDay, 30),
+        (ParseError::DurationInvalidNumber, 37),
+        (ParseError::DateTooLarge, 49),
+        (ParseError::RelativeNotSupported, 55),
+        (ParseError::InvalidZoneName, 56),
+        (ParseError::YearZero, 57),
+        (ParseError::AmbiguousTimestamp, 58),
+    ] {
+        assert_eq!(error.code(), code, "{error:?}");
+        assert_eq!(ParseError::from_code(code), Some(error));
+    }
+}
+
+#[test]
+fn date_trim_whitespace() {
+    let config = DateConfigBuilder::new().trim_whitespace(true).build();
+    for input in [
+        " 1577836800 ",
+        " 2020-01-01 ",
+        "\t2020-01-01\n",
+        "1577836800\r\n",
+        "2020-01-01",
+    ] {
+        let d = Date::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(d.to_string(), "2020-01-01", "{input:?}");
+    }
+    let d = Date::parse_bytes_rfc3339_with_config(b" 2020-01-01 ", &config).unwrap();
+    assert_eq!(d.to_string(), "2020-01-01");
+    assert_eq!(
+        Date::parse_bytes_with_config(b" 2020-01-01 x", &config),
+        Err(ParseError::ExtraCharacters)
+    );
+    assert_eq!(Date::parse_bytes_with_config(b"  ", &config), Err(ParseError::TooShort));
+
+    // without the flag whitespace is rejected
+    let config = DateConfigBuilder::new().build();
+    for input in [" 1577836800 ", " 2020-01-01 ", "2020-01-01 "] {
+        assert!(
+            Date::parse_bytes_with_config(input.as_bytes(), &config).is_err(),
+            "{input:?}"
+        );
+    }
+}
+
+#[test]
+fn date_forbid_year_zero() {
+    let config = DateConfigBuilder::new().forbid_year_zero(true).build();
+    assert_eq!(
+        Date::parse_bytes_with_config(b"0000-01-01", &config),
+        Err(ParseError::YearZero)
+    );
+    assert_eq!(
+        Date::parse_bytes_rfc3339_with_config(b"0000-12-31", &config),
+        Err(ParseError::YearZero)
+    );
+    let d = Date::parse_bytes_with_config(b"0001-01-01", &config).unwrap();
+    assert_eq!(d.to_string(), "0001-01-01");
+
+    let config = DateConfigBuilder::new().build();
+    let d = Date::parse_bytes_with_config(b"0000-01-01", &config).unwrap();
+    assert_eq!(d.to_string(), "0000-01-01");
+    assert_eq!(Date::parse_str("0000-01-01").unwrap().year, 0);
+}
+
+#[test]
+fn zoned_datetime() {
+    let zdt = ZonedDateTime::parse_str("2020-01-01T12:00:00-05:00[America/New_York]").unwrap();
+    assert_eq!(zdt.zone_name, "America/New_York");
+    assert_eq!(zdt.dt, DateTime::parse_str("2020-01-01T12:00:00-05:00").unwrap());
+    assert_eq!(zdt.dt.timestamp_tz(), 1_577_898_000);
+    assert_eq!(zdt.to_string(), "2020-01-01T12:00:00-05:00[America/New_York]");
+    assert_eq!(zdt.to_string().parse::<ZonedDateTime>().unwrap(), zdt);
+
+    let zdt = ZonedDateTime::parse_str("2020-01-01T12:00:00[Etc/GMT+5]").unwrap();
+    assert_eq!(zdt.dt.time.tz_offset, None);
+    assert_eq!(zdt.to_string(), "2020-01-01T12:00:00[Etc/GMT+5]");
+
+    let zdt = ZonedDateTime {
+        dt: DateTime::parse_str("2020-06-01T12:00:00+01:00").unwrap(),
+        zone_name: "Europe/London".to_string(),
+    };
+    assert_eq!(zdt.to_string(), "2020-06-01T12:00:00+01:00[Europe/London]");
+}
+
+#[test]
+fn zoned_datetime_errors() {
+    for input in [
+        "2020-01-01T12:00:00-05:00",
+        "2020-01-01T12:00:00-05:00[]",
+        "2020-01-01T12:00:00-05:00[America/New York]",
+        "2020-01-01T12:00:00-05:00America/New_York]",
+        "2020-01-01T12:00:00-05:00[America/New_York",
+        "2020-01-01T12:00:00-05:00[America/New_York]x",
+    ] {
+        assert_eq!(
+            ZonedDateTime::parse_str(input),
+            Err(ParseError::InvalidZoneName),
+            "{input:?}"
+        );
+    }
+    assert_eq!(
+        ZonedDateTime::parse_str("2020-01-01T12:61:00-05:00[America/New_York]"),
+        Err(ParseError::OutOfRangeMinute)
+    );
+    assert_eq!(
+        ZonedDateTime::parse_str("[America/New_York]"),
+        Err(ParseError::TooShort)
+    );
+}
+
+#[test]
+fn duration_case_insensitive_designators() {
+    let config = DurationConfigBuilder::new().case_insensitive_designators(true).build();
+    for (input, expected) in [
+        ("p1y2m3dt4h5m6s", "P1Y63DT4H5M6S"),
+        ("P1y2M3dT4h5m6S", "P1Y63DT4H5M6S"),
+        ("p2w", "P14D"),
+        ("pt1m", "PT1M"),
+        ("p1m", "P30D"),
+        ("pt1.5h", "PT1H30M"),
+        ("-p1d", "-P1D"),
+        ("P1DT2H", "P1DT2H"),
+    ] {
+        let d = Duration::parse_bytes_with_duration_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(d.to_string(), expected, "{input:?}");
+    }
+    assert_eq!(
+        Duration::parse_bytes_with_duration_config(b"pt1d", &config),
+        Err(ParseError::DurationInvalidTimeUnit)
+    );
+    assert_eq!(
+        Duration::parse_bytes_with_duration_config(b"p1dtt1h", &config),
+        Err(ParseError::DurationTRepeated)
+    );
+
+    // lowercase designators are rejected by default
+    assert_eq!(
+        Duration::parse_str("p1y2m3dt4h5m6s"),
+        Err(ParseError::DurationInvalidNumber)
+    );
+    assert_eq!(Duration::parse_str("P1d"), Err(ParseError::DurationInvalidDateUnit));
+    assert_eq!(Duration::parse_str("P1DT2h"), Err(ParseError::DurationInvalidTimeUnit));
+    assert!(Duration::parse_str("P1Dt2H").is_err());
+}
+
+#[test]
+fn time_basic_format() {
+    let config = TimeConfigBuilder::new().allow_basic_format(true).build();
+    for (input, expected) in [
+        ("121314", "12:13:14"),
+        ("1213", "12:13:00"),
+        ("121314Z", "12:13:14Z"),
+        ("1213Z", "12:13:00Z"),
+        ("121314+0200", "12:13:14+02:00"),
+        ("121314.5-05:00", "12:13:14.500000-05:00"),
+        ("235959,123", "23:59:59.123000"),
+        // the extended format is still accepted
+        ("12:13:14", "12:13:14"),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.to_string(), expected, "{input:?}");
+    }
+    for (input, error) in [
+        ("12131", ParseError::InvalidCharSecond),
+        ("1213x", ParseError::InvalidCharTzSign),
+        ("1213145", ParseError::InvalidCharTzSign),
+        ("2513", ParseError::OutOfRangeHour),
+        ("1260", ParseError::OutOfRangeMinute),
+        ("121360", ParseError::OutOfRangeSecond),
+        ("121", ParseError::TooShort),
+    ] {
+        let result = Time::parse_bytes_with_config(input.as_bytes(), &config);
+        assert_eq!(result, Err(error), "{input:?}");
+    }
+
+    // rejected by default
+    assert_eq!(Time::parse_str("121314"), Err(ParseError::InvalidCharTimeSep));
+}
+
+#[test]
+fn datetime_skip_bom() {
+    let config = TimeConfigBuilder::new().skip_bom(true).build();
+    let input = "\u{FEFF}2020-01-01T12:13:14Z";
+    assert_eq!(input.as_bytes()[..3], [0xEF, 0xBB, 0xBF]);
+    let dt = DateTime::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
+    let dt = DateTime::parse_bytes_rfc3339_with_config(input.as_bytes(), &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
+    let (dt, nanosecond) =
+        DateTime::parse_bytes_nanos("\u{FEFF}2020-01-01T12:13:14.123456789Z".as_bytes(), &config).unwrap();
+    assert_eq!(
+        (dt.to_string().as_str(), nanosecond),
+        ("2020-01-01T12:13:14.123456Z", 789)
+    );
+    let dt = DateTime::parse_bytes_with_config("\u{FEFF}1577880794".as_bytes(), &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14");
+    let t = Time::parse_bytes_with_config("\u{FEFF}12:13:14".as_bytes(), &config).unwrap();
+    assert_eq!(t.to_string(), "12:13:14");
+    let d = Duration::parse_bytes_with_config("\u{FEFF}P1D".as_bytes(), &config).unwrap();
+    assert_eq!(d.to_string(), "P1D");
+
+    // with whitespace trimming, the BOM comes first
+    let config = TimeConfigBuilder::new().skip_bom(true).trim_whitespace(true).build();
+    let dt = DateTime::parse_bytes_with_config("\u{FEFF} 2020-01-01T12:13:14Z ".as_bytes(), &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14Z");
+
+    // only a single leading BOM is skipped
+    let config = TimeConfigBuilder::new().skip_bom(true).build();
+    assert!(DateTime::parse_bytes_with_config("\u{FEFF}\u{FEFF}2020-01-01T12:13:14Z".as_bytes(), &config).is_err());
+    assert!(DateTime::parse_bytes_with_config("2020-01-01T12:13:14Z\u{FEFF}".as_bytes(), &config).is_err());
+
+    // the BOM is an error by default
+    assert!(DateTime::parse_str(input).is_err());
+}
+
+#[test]
+fn datetime_missing_sep() {
+    let config = TimeConfigBuilder::new().allow_missing_datetime_sep(true).build();
+    for (input, expected) in [
+        ("2020-01-0112:13:14", "2020-01-01T12:13:14"),
+        ("2020-01-0112:13:14.5Z", "2020-01-01T12:13:14.500000Z"),
+        ("2020-01-0100:00+02:00", "2020-01-01T00:00:00+02:00"),
+        ("2020-01-01T12:13:14", "2020-01-01T12:13:14"),
+        ("2020-01-01 12:13:14", "2020-01-01T12:13:14"),
+    ] {
+        let dt = DateTime::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(dt.to_string(), expected, "{input:?}");
+    }
+    let (dt, nanosecond) = DateTime::parse_bytes_nanos(b"2020-01-0112:13:14.123456789", &config).unwrap();
+    assert_eq!(
+        (dt.to_string().as_str(), nanosecond),
+        ("2020-01-01T12:13:14.123456", 789)
+    );
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"2020-01-011:13:14", &config),
+        Err(ParseError::TimeFieldNotPadded)
+    );
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"2020-01-01x12:13:14", &config),
+        Err(ParseError::InvalidCharDateTimeSep)
+    );
+
+    // combined with the basic format, the time may also be compact
+    let config = TimeConfigBuilder::new()
+        .allow_missing_datetime_sep(true)
+        .allow_basic_format(true)
+        .build();
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01121314", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14");
+
+    // rejected by default
+    assert_eq!(
+        DateTime::parse_str("2020-01-0112:13:14"),
+        Err(ParseError::InvalidCharDateTimeSep)
+    );
+}
+
+#[test]
+fn time_hour_only_offset() {
+    let config = TimeConfigBuilder::new().allow_hour_only_offset(true).build();
+    for (input, tz_offset) in [
+        ("12:13:14+02", 7_200),
+        ("12:13:14-05", -18_000),
+        ("12:13:14.5+02", 7_200),
+        ("12:13:14\u{2212}05", -18_000),
+        ("12:13:14+02:30", 9_000),
+        ("12:13:14+0230", 9_000),
+    ] {
+        let t = Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap();
+        assert_eq!(t.tz_offset, Some(tz_offset), "{input:?}");
+    }
+    let dt = DateTime::parse_bytes_with_config(b"2020-01-01T12:13:14+02", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-01-01T12:13:14+02:00");
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13:14+24", &config),
+        Err(ParseError::OutOfRangeTz)
+    );
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13:14+02Z", &config),
+        Err(ParseError::DuplicateTimezone)
+    );
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13:14+02x", &config),
+        Err(ParseError::ExtraCharacters)
+    );
+    assert_eq!(
+        Time::parse_bytes_with_config(b"12:13:14+021", &config),
+        Err(ParseError::InvalidCharTzMinute)
+    );
+
+    // minutes are required by default
+    assert_eq!(Time::parse_str("12:13:14+02"), Err(ParseError::InvalidCharTzMinute));
+    assert_eq!(
+        DateTime::parse_str("2020-01-01T12:13:14-05"),
+        Err(ParseError::InvalidCharTzMinute)
+    );
+}
+
+#[test]
+fn test_time_config_builder() {
+    assert_eq!(
+        TimeConfigBuilder::new().build(),
+        TimeConfig {
+            microseconds_precision_overflow_behavior: MicrosecondsPrecisionOverflowBehavior::Error,
+            unix_timestamp_offset: None,
+            allow_end_of_day_24: false,
+            float_rounding: RoundingMode::Round,
+            allow_single_digit_fields: false,
+            trim_whitespace: false,
+            allow_space_before_offset: false,
+            allow_tz_names: false,
+            allow_tz_offset_seconds: false,
+            allow_fractional_minutes: false,
+            allow_hour_only: false,
+            allow_timestamp_at_prefix: false,
+            allow_space_padding: false,
+            max_input_len: None,
+            fraction_separator: FractionSep::Either,
+            allow_decimal_tz_offset: false,
+            allow_basic_format: false,
+            skip_bom: false,
+            allow_hour_only_offset: false,
+            timestamp_unit: TimestampUnit::Infer,
+            allow_missing_datetime_sep: false,
+        }
+    );
+    assert_eq!(TimeConfigBuilder::new().build(), TimeConfig::builder().build());
+}
+
+#[test]
+fn test_duration_config_builder() {
+    assert_eq!(
+        DurationConfigBuilder::new().build(),
+        DurationConfig {
+            time_config: TimeConfigBuilder::new().build(),
+            allow_dotnet_timespan: false,
+            allow_signed_components: false,
+            allow_zero_duration_shorthand: false,
+            case_insensitive_designators: false,
+        }
+    );
+    assert_eq!(DurationConfigBuilder::new().build(), DurationConfig::builder().build());
+    let time_config = TimeConfigBuilder::new().trim_whitespace(true).build();
+    let config = DurationConfigBuilder::new().time_config(time_config.clone()).build();
+    assert_eq!(config.time_config, time_config);
+    assert_eq!(
+        Duration::parse_bytes_with_config(b" PT1H ", &time_config),
+        Duration::parse_bytes_with_duration_config(b" PT1H ", &config)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn parse_error_std_error() {
+    fn parse(s: &str) -> Result<Date, Box<dyn std::error::Error>> {
+        Ok(Date::parse_str(s)?)
+    }
+    assert_eq!(parse("2020-01-01").unwrap().to_string(), "2020-01-01");
+    let error = parse("2020-01-32").unwrap_err();
+    assert_eq!(error.to_string(), "out_of_range_day");
+    assert_eq!(error.downcast_ref::<ParseError>(), Some(&ParseError::OutOfRangeDay));
+
+    let error: Box<dyn std::error::Error> = MicrosecondsPrecisionOverflowBehavior::try_from("foo")
+        .unwrap_err()
+        .into();
+    assert_eq!(
+        error.to_string(),
+        "unknown_microseconds_precision_overflow_behavior_string"
+    );
+}
+
+#[test]
+fn date_dash_err() {
+    let error = Date::parse_str("-").unwrap_err();
+    assert_eq!(error, ParseError::TooShort);
+    assert_eq!(error.to_string(), "too_short");
+    assert_eq!(error.get_documentation(), Some("input is too short"));
+}
+
+#[test]
+fn number_dash_err() {
+    assert!(int_parse_str("-").is_none());
+    assert!(int_parse_str("+").is_none());
+    assert!(int_parse_bytes(b"-").is_none());
+    assert!(int_parse_bytes(b"+").is_none());
+
+    assert!(matches!(float_parse_str("-"), IntFloat::Err));
+    assert!(matches!(float_parse_str("+"), IntFloat::Err));
+    assert!(matches!(float_parse_bytes(b"-"), IntFloat::Err));
+    assert!(matches!(float_parse_bytes(b"+"), IntFloat::Err));
+}
+
+#[test]
+fn test_time_end_of_day_24() {
+    let config = TimeConfigBuilder::new().allow_end_of_day_24(true).build();
+    let t = Time::parse_bytes_with_config(b"24:00:00", &config).unwrap();
+    assert_eq!(t.to_string(), "00:00:00");
+    let t = Time::parse_bytes_with_config(b"24:00:00.0", &config).unwrap();
+    assert_eq!(t.to_string(), "00:00:00");
+    let t = Time::parse_bytes_with_config(b"24:00", &config).unwrap();
+    assert_eq!(t.to_string(), "00:00:00");
+    let t = Time::parse_bytes_with_config(b"24:00:00+01:00", &config).unwrap();
+    assert_eq!(t.to_string(), "00:00:00+01:00");
+
+    for input in ["24:00:01", "24:01:00", "24:00:00.000001", "25:00:00"] {
+        assert_eq!(
+            Time::parse_bytes_with_config(input.as_bytes(), &config).unwrap_err(),
+            ParseError::OutOfRangeHour,
+            "{input}"
+        );
+    }
+    // still rejected by default
+    assert_eq!(Time::parse_str("24:00:00").unwrap_err(), ParseError::OutOfRangeHour);
+}
+
+#[test]
+fn test_datetime_end_of_day_24() {
+    let config = TimeConfigBuilder::new().allow_end_of_day_24(true).build();
+    let dt = DateTime::parse_bytes_with_config(b"2020-02-28T24:00:00", &config).unwrap();
+    assert_eq!(dt.to_string(), "2020-02-29T00:00:00");
+    let dt = DateTime::parse_bytes_with_config(b"2020-12-31T24:00:00Z", &config).unwrap();
+    assert_eq!(dt.to_string(), "2021-01-01T00:00:00Z");
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"9999-12-31T24:00:00", &config).unwrap_err(),
+        ParseError::DateTooLarge
+    );
+    assert_eq!(
+        DateTime::parse_bytes_with_config(b"2020-12-31T24:00:01", &config).unwrap_err(),
+        ParseError::OutOfRangeHour
+    );
+    assert_eq!(
+        DateTime::parse_str("2020-12-31T24:00:00").unwrap_err(),
+        ParseError::OutOfRangeHour
+    );
+
+    let d = Duration::parse_bytes_with_config(b"P0000-00-01T24:00:00", &config).unwrap();
+    assert_eq!(d.to_string(), "P2D");
 }